@@ -31,6 +31,18 @@ pub enum ErrorCode {
     #[msg("Invalid global config: must be canonical PDA")]
     InvalidGlobalConfig,
 
+    /// 6006: No config change is queued
+    #[msg("No pending config update")]
+    NoPendingConfig,
+
+    /// 6007: Queued config change cannot be applied before its effective time
+    #[msg("Config update timelock has not elapsed")]
+    ConfigTimelockActive,
+
+    /// 6008: Pause flags contain unknown bits
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
     // ============================================================
     // State Transition Errors (6100-6199)
     // ============================================================
@@ -55,7 +67,7 @@ pub enum ErrorCode {
     #[msg("Protocol is paused")]
     ProtocolPaused,
 
-    /// 6105: Cannot cancel market in current state (only PROPOSED/APPROVED)
+    /// 6105: Cannot cancel market in current state (FINALIZED markets cannot be cancelled)
     #[msg("Cannot cancel market in current state")]
     CannotCancelMarket,
 
@@ -63,6 +75,18 @@ pub enum ErrorCode {
     #[msg("Market is already cancelled")]
     MarketAlreadyCancelled,
 
+    /// 6107: Trader refunds must be claimed before the market can be closed
+    #[msg("Trader refunds still outstanding")]
+    RefundsOutstanding,
+
+    /// 6108: Market is neither cancelled nor a rejected proposal
+    #[msg("Market cannot be reclaimed in current state")]
+    CannotReclaimMarket,
+
+    /// 6109: Market has unsettled claims and the claim deadline has not passed
+    #[msg("Market cannot be closed yet")]
+    MarketNotClosable,
+
     // ============================================================
     // Trading Errors (6200-6299)
     // ============================================================
@@ -91,6 +115,26 @@ pub enum ErrorCode {
     #[msg("Trade amount below minimum (0.00001 SOL)")]
     TradeTooSmall,
 
+    /// 6206: Position still holds an unclaimed payout or refund
+    #[msg("Position cannot be closed until its payout is claimed")]
+    PositionNotClosable,
+
+    /// 6207: Market type does not allow selling (parimutuel stakes are final)
+    #[msg("Selling is not supported for this market type")]
+    SellNotSupported,
+
+    /// 6208: Market type has no liquidity pool (parimutuel markets)
+    #[msg("Liquidity provision is not supported for this market type")]
+    LiquidityNotSupported,
+
+    /// 6209: Third-party LP shares must be redeemed before the creator reclaims
+    #[msg("Liquidity providers still hold shares in this market")]
+    LiquidityOutstanding,
+
+    /// 6210: LPs redeem only after winners have claimed or the claim window closed
+    #[msg("Winners have not finished claiming from this market")]
+    WinningsOutstanding,
+
     // ============================================================
     // Resolution Errors (6300-6399)
     // ============================================================
@@ -135,6 +179,30 @@ pub enum ErrorCode {
     #[msg("Already disputed")]
     AlreadyDisputed,
 
+    /// 6310: Resolver fees already withdrawn for this market
+    #[msg("Resolver fees already claimed")]
+    ResolverFeesAlreadyClaimed,
+
+    /// 6311: No resolver fees to claim (none accumulated, or forfeited by INVALID outcome)
+    #[msg("No resolver fees to claim")]
+    NoResolverFees,
+
+    /// 6312: Dispute must propose an outcome different from the proposed one
+    #[msg("Disputed outcome must differ from proposed outcome")]
+    InvalidDisputeOutcome,
+
+    /// 6313: Claim window after finalization has expired
+    #[msg("Claim period ended")]
+    ClaimPeriodEnded,
+
+    /// 6314: Claim window after finalization has not expired yet (cannot sweep)
+    #[msg("Claim period not ended")]
+    ClaimPeriodNotEnded,
+
+    /// 6315: claim_many remaining accounts must be writable (market, position) pairs
+    #[msg("Invalid claim accounts")]
+    InvalidClaimAccounts,
+
     // ============================================================
     // Authorization Errors (6400-6499)
     // ============================================================
@@ -155,6 +223,34 @@ pub enum ErrorCode {
     #[msg("Insufficient reputation")]
     InsufficientReputation,
 
+    /// 6404: Signer is not part of the admin multisig
+    #[msg("Not a multisig signer")]
+    NotMultisigSigner,
+
+    /// 6405: Invalid multisig signer set or threshold
+    #[msg("Invalid multisig configuration")]
+    InvalidMultisigConfig,
+
+    /// 6406: Signer already approved this admin action
+    #[msg("Admin action already approved by signer")]
+    AlreadyApproved,
+
+    /// 6407: Admin action has not reached the multisig threshold
+    #[msg("Insufficient multisig approvals")]
+    InsufficientApprovals,
+
+    /// 6408: Admin action approvals were collected under a replaced signer set
+    #[msg("Admin action is stale")]
+    StaleAdminAction,
+
+    /// 6409: Accounts passed to execute_admin_action do not match the action
+    #[msg("Invalid accounts for admin action")]
+    InvalidAdminActionAccounts,
+
+    /// 6410: Admin action can still execute and only its proposer may cancel it
+    #[msg("Admin action is still active")]
+    AdminActionActive,
+
     // ============================================================
     // Math Errors (6500-6599)
     // ============================================================
@@ -199,7 +295,7 @@ pub enum ErrorCode {
     // Validation Errors (6600-6699)
     // ============================================================
 
-    /// 6600: Invalid LMSR b parameter (must be >= MIN_B)
+    /// 6600: Invalid LMSR b parameter (must be within MIN_B..=MAX_B)
     #[msg("Invalid LMSR b parameter")]
     InvalidBParameter,
 
@@ -231,6 +327,10 @@ pub enum ErrorCode {
     #[msg("Reserved fields must be zero")]
     InvalidReservedField,
 
+    /// 6608: Invalid LS-LMSR alpha (must be within MIN_ALPHA..=MAX_ALPHA)
+    #[msg("Invalid LS-LMSR alpha parameter")]
+    InvalidAlphaParameter,
+
     // ============================================================
     // Voting Errors (6700-6799)
     // ============================================================
@@ -242,4 +342,8 @@ pub enum ErrorCode {
     /// 6701: Market not in correct state for voting
     #[msg("Invalid state for voting")]
     InvalidStateForVoting,
+
+    /// 6702: Vote record cannot be closed while its voting phase is ongoing
+    #[msg("Voting phase not ended")]
+    VotingNotEnded,
 }
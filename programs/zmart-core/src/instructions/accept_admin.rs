@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::error::ErrorCode;
+
+/// Accept a pending admin nomination (step 2 of 2)
+///
+/// The wallet recorded by propose_admin signs to take over as admin.
+/// Clears `pending_admin` afterwards.
+///
+/// # Errors
+///
+/// * `InvalidAdmin` - If no handover is pending or signer is not the nominee
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Nominated admin
+    pub new_admin: Signer<'info>,
+
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.pending_admin != Pubkey::default() @ ErrorCode::InvalidAdmin,
+        constraint = global_config.pending_admin == new_admin.key() @ ErrorCode::InvalidAdmin
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Handler for accept_admin instruction
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Admin transferred from {} to {}", previous_admin, config.admin);
+
+    Ok(())
+}
+
+/// Event emitted when admin handover completes
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_config() -> GlobalConfig {
+        GlobalConfig {
+            admin: Pubkey::new_unique(),
+            backend_authority: Pubkey::new_unique(),
+            protocol_fee_wallet: Pubkey::new_unique(),
+            protocol_fee_bps: 300,
+            resolver_reward_bps: 200,
+            liquidity_provider_fee_bps: 500,
+            proposal_approval_threshold: 7000,
+            dispute_success_threshold: 6000,
+            min_resolution_delay: 86_400,
+            dispute_period: 259_200,
+            min_resolver_reputation: 8000,
+            paused_operations: 0,
+            claim_period: 31_536_000,
+            pending_admin: Pubkey::default(),
+            guardian: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_two_step_handover() {
+        let mut config = create_test_config();
+        let old_admin = config.admin;
+        let new_admin = Pubkey::new_unique();
+
+        // Step 1: proposal does not change admin
+        config.pending_admin = new_admin;
+        assert_eq!(config.admin, old_admin);
+
+        // Step 2: only the nominee can accept
+        let signer = new_admin;
+        assert!(config.pending_admin != Pubkey::default() && config.pending_admin == signer);
+        config.admin = signer;
+        config.pending_admin = Pubkey::default();
+
+        assert_eq!(config.admin, new_admin);
+        assert_eq!(config.pending_admin, Pubkey::default());
+    }
+
+    #[test]
+    fn test_no_pending_admin_rejects_default_signer() {
+        let config = create_test_config();
+
+        // With nothing pending, even a default key cannot accept
+        let signer = Pubkey::default();
+        assert!(!(config.pending_admin != Pubkey::default() && config.pending_admin == signer));
+    }
+}
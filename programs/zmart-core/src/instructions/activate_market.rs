@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use crate::state::{FeeSchedule, GlobalConfig, MarketAccount, MarketState};
 use crate::error::ErrorCode;
 
 /// Activate an approved market, transitioning APPROVED → ACTIVE
@@ -8,6 +8,9 @@ use crate::error::ErrorCode;
 /// activate it, making it available for trading. This is the final step
 /// before users can buy and sell shares.
 ///
+/// With `snapshot_fees`, the market freezes the current fee schedule and is
+/// unaffected by later config changes; otherwise it follows GlobalConfig.
+///
 /// # Errors
 ///
 /// * `Unauthorized` - If signer is not admin or creator
@@ -36,7 +39,7 @@ pub struct ActivateMarket<'info> {
 }
 
 /// Handler for activate_market instruction
-pub fn handler(ctx: Context<ActivateMarket>) -> Result<()> {
+pub fn handler(ctx: Context<ActivateMarket>, snapshot_fees: bool) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let global_config = &ctx.accounts.global_config;
     let authority = ctx.accounts.authority.key();
@@ -69,6 +72,11 @@ pub fn handler(ctx: Context<ActivateMarket>) -> Result<()> {
     let clock = Clock::get()?;
     market.activated_at = clock.unix_timestamp;
 
+    if snapshot_fees {
+        market.has_fee_snapshot = true;
+        market.fee_snapshot = global_config.fee_schedule();
+    }
+
     msg!(
         "Market {:?} activated by {} (admin: {}, creator: {})",
         market.market_id,
@@ -82,6 +90,7 @@ pub fn handler(ctx: Context<ActivateMarket>) -> Result<()> {
         market_id: market.market_id,
         creator: market.creator,
         initial_liquidity: market.initial_liquidity,
+        fee_snapshot: market.has_fee_snapshot.then_some(market.fee_snapshot),
         timestamp: market.activated_at,
     });
 
@@ -93,6 +102,7 @@ pub struct MarketActivated {
     pub market_id: [u8; 32],
     pub creator: Pubkey,
     pub initial_liquidity: u64,
+    pub fee_snapshot: Option<FeeSchedule>,
     pub timestamp: i64,
 }
 
@@ -111,39 +121,12 @@ mod tests {
             b_parameter: 1_000_000_000,
             initial_liquidity: liquidity,
             current_liquidity: liquidity,
-            shares_yes: 0,
-            shares_no: 0,
-            total_volume: 0,
             created_at: 1730000000,
             approved_at: 1730100000,
-            activated_at: 0,
-            resolution_proposed_at: 0,
-            resolved_at: 0,
-            finalized_at: 0,
-            resolver: Pubkey::default(),
-            proposed_outcome: None,
-            final_outcome: None,
-            ipfs_evidence_hash: [0; 46],
-            dispute_initiated_at: 0,
-            dispute_initiator: Pubkey::default(),
-            accumulated_protocol_fees: 0,
-            accumulated_resolver_fees: 0,
-            accumulated_lp_fees: 0,
             proposal_likes: 80,
             proposal_dislikes: 20,
             proposal_total_votes: 100,
-            resolution_agree: 0,
-            resolution_disagree: 0,
-            resolution_total_votes: 0,
-            dispute_agree: 0,
-            dispute_disagree: 0,
-            dispute_total_votes: 0,
-            was_disputed: false,
-            is_cancelled: false,
-            cancelled_at: None,
-            bump: 255,
-            is_locked: false,
-            reserved: [0; 119],
+            ..MarketAccount::test_market()
         }
     }
 
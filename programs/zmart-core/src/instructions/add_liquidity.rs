@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, LiquidityPosition, MarketAccount, MarketLock, PAUSE_LIQUIDITY};
+
+/// Deposit liquidity into a market in exchange for LP shares
+///
+/// Any wallet can deepen a PROPOSED or APPROVED market's pool before trading
+/// starts. LP shares are minted at the pool's current lamports-per-share
+/// rate and b grows in proportion to the pool, so the deposit also covers
+/// its share of the market maker's worst-case loss. LP shares are redeemed
+/// with remove_liquidity for a pro-rata cut of the pool and LP fees.
+///
+/// # Arguments
+/// * `amount` - Lamports to deposit
+///
+/// # Errors
+/// * `LiquidityNotSupported` - Parimutuel markets have no pool
+/// * `InvalidMarketState` - Trading has started
+/// * `InvalidBParameter` - An LMSR market's b would exceed MAX_B
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = LiquidityPosition::LEN,
+        seeds = [b"lp-position", market.key().as_ref(), provider.key().as_ref()],
+        bump
+    )]
+    pub lp_position: Account<'info, LiquidityPosition>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let lp_position = &mut ctx.accounts.lp_position;
+
+    ctx.accounts.global_config.require_not_paused(PAUSE_LIQUIDITY)?;
+    market.require_not_frozen()?;
+
+    let lp_shares = market.add_liquidity(amount)?;
+
+    if lp_position.provider == Pubkey::default() {
+        // First deposit into this market
+        lp_position.market = market.key();
+        lp_position.provider = ctx.accounts.provider.key();
+        lp_position.lp_shares = 0;
+        lp_position.total_deposited = 0;
+        lp_position.total_withdrawn = 0;
+        lp_position.bump = ctx.bumps.lp_position;
+    }
+
+    lp_position.lp_shares = lp_position.lp_shares
+        .checked_add(lp_shares)
+        .ok_or(ErrorCode::OverflowError)?;
+    lp_position.total_deposited = lp_position.total_deposited
+        .checked_add(amount)
+        .ok_or(ErrorCode::OverflowError)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: market.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(LiquidityAdded {
+        market_id: market.market_id,
+        provider: ctx.accounts.provider.key(),
+        amount,
+        lp_shares,
+        new_b_parameter: market.b_parameter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub market_id: [u8; 32],
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub lp_shares: u64,
+    pub new_b_parameter: u64,
+    pub timestamp: i64,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use crate::state::{GlobalConfig, MarketAccount, MarketState, PAUSE_VOTE};
 
 /// Aggregate dispute votes and transition state based on threshold
 #[derive(Accounts)]
@@ -38,6 +38,9 @@ pub fn handler(
     let global_config = &ctx.accounts.global_config;
     let clock = Clock::get()?;
 
+    global_config.require_not_paused(PAUSE_VOTE)?;
+    market.require_not_frozen()?;
+
     // SECURITY FIX (Finding #4 - Week 3): Verify canonical global config PDA
     // Prevents attacker from creating fake global_config with their own backend_authority
     let (canonical_config, _) = Pubkey::find_program_address(
@@ -96,10 +99,15 @@ pub fn handler(
         // We must use direct assignment here as it's outside the normal FSM
         market.state = MarketState::Resolving;
         market.was_disputed = true;
+        // Disputed outcome replaces the rejected one, so finalization uses it
+        market.proposed_outcome = market.outcome_after_dispute(true);
         // Note: resolution_proposed_at remains set (for dispute period calculation)
     } else {
         // <60% agree: Original resolution accepted, finalize market
-        market.transition_state(MarketState::Finalized)?;
+        let final_outcome = market
+            .outcome_after_dispute(false)
+            .ok_or(ErrorCode::NoResolutionProposed)?;
+        market.finalize(final_outcome, clock.unix_timestamp, global_config.claim_window())?;
         market.resolved_at = clock.unix_timestamp;
         market.was_disputed = true;
     }
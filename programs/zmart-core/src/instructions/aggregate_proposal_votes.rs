@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use crate::state::{GlobalConfig, MarketAccount, MarketState, PAUSE_VOTE};
 
 /// Aggregate proposal votes and transition state if threshold met
+///
+/// Proposals at or above `proposal_approval_threshold` stay PROPOSED until the
+/// admin calls approve_proposal. Proposals still below the threshold once the
+/// voting window has elapsed move to the terminal REJECTED state, from which
+/// the creator can reclaim the market via reclaim_market.
 #[derive(Accounts)]
 pub struct AggregateProposalVotes<'info> {
     /// Market account (must be in PROPOSED state)
@@ -38,6 +43,9 @@ pub fn handler(
     let global_config = &ctx.accounts.global_config;
     let clock = Clock::get()?;
 
+    global_config.require_not_paused(PAUSE_VOTE)?;
+    market.require_not_frozen()?;
+
     // SECURITY FIX (Finding #4 - Week 3): Verify canonical global config PDA
     // Prevents attacker from creating fake global_config with their own backend_authority
     let (canonical_config, _) = Pubkey::find_program_address(
@@ -96,6 +104,17 @@ pub fn handler(
     // Calculate display percentage (0-100) for event
     let approval_percentage = (likes_bps / 100) as u8;
 
+    // Reject proposals that failed the vote once the voting window is over
+    if market.proposal_rejected(global_config.proposal_approval_threshold, clock.unix_timestamp) {
+        market.transition_state(MarketState::Rejected)?;
+
+        emit!(ProposalRejected {
+            market_id: market.market_id,
+            approval_percentage,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     // Emit event (always, for monitoring)
     emit!(ProposalAggregated {
         market_id: market.market_id,
@@ -119,3 +138,11 @@ pub struct ProposalAggregated {
     pub approved: bool,          // true if >= 70%
     pub timestamp: i64,
 }
+
+/// Proposal failed community vote and moved to REJECTED state
+#[event]
+pub struct ProposalRejected {
+    pub market_id: [u8; 32],
+    pub approval_percentage: u8, // 0-100
+    pub timestamp: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::instructions::ConfigUpdated;
+use crate::state::{GlobalConfig, PendingConfig};
+use crate::error::ErrorCode;
+
+/// Apply a queued config change once its timelock has passed
+///
+/// Permissionless: anyone can crank the change into GlobalConfig after
+/// `effective_at`. Markets that snapshotted fees at activation keep them.
+///
+/// # Errors
+///
+/// * `NoPendingConfig` - If no change is queued
+/// * `ConfigTimelockActive` - If the timelock has not elapsed
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Queued config change
+    #[account(
+        mut,
+        seeds = [b"pending-config"],
+        bump = pending_config.bump,
+        constraint = pending_config.is_pending @ ErrorCode::NoPendingConfig
+    )]
+    pub pending_config: Account<'info, PendingConfig>,
+}
+
+/// Handler for apply_config instruction
+pub fn handler(ctx: Context<ApplyConfig>) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_config;
+    let config = &mut ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(pending.is_ready(now), ErrorCode::ConfigTimelockActive);
+
+    pending.apply_to(config);
+    pending.is_pending = false;
+
+    emit!(ConfigUpdated {
+        protocol_fee_bps: config.protocol_fee_bps,
+        resolver_reward_bps: config.resolver_reward_bps,
+        liquidity_provider_fee_bps: config.liquidity_provider_fee_bps,
+        proposal_approval_threshold: config.proposal_approval_threshold,
+        dispute_success_threshold: config.dispute_success_threshold,
+        min_resolution_delay: config.min_resolution_delay,
+        dispute_period: config.dispute_period,
+        claim_period: config.claim_window(),
+        timestamp: now,
+    });
+
+    msg!("Queued global config update applied");
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, PendingAdminAction};
+use crate::error::ErrorCode;
+
+/// Approve a pending admin action
+///
+/// # Errors
+///
+/// * `NotMultisigSigner` - If signer is not in the signer set
+/// * `StaleAdminAction` - If the signer set changed since the action was proposed
+/// * `AlreadyApproved` - If signer already approved
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    /// Multisig signer approving the action
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin-multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        constraint = pending_action.signer_set_seq == multisig.signer_set_seq @ ErrorCode::StaleAdminAction
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+}
+
+/// Handler for approve_admin_action instruction
+pub fn handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let index = ctx.accounts.multisig.signer_index(&ctx.accounts.signer.key())?;
+    let pending_action = &mut ctx.accounts.pending_action;
+
+    pending_action.approve(index)?;
+
+    emit!(AdminActionApproved {
+        pending_action: pending_action.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: pending_action.approval_count() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a signer approves an admin action
+#[event]
+pub struct AdminActionApproved {
+    pub pending_action: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
@@ -111,44 +111,12 @@ mod tests {
         let total = likes + dislikes;
         MarketAccount {
             market_id: [1; 32],
-            creator: Pubkey::new_unique(),
-            state: MarketState::Proposed,
             b_parameter: 1_000_000_000,
-            initial_liquidity: 10_000_000_000,
-            current_liquidity: 10_000_000_000,
-            shares_yes: 0,
-            shares_no: 0,
-            total_volume: 0,
             created_at: 1730000000,
-            approved_at: 0,
-            activated_at: 0,
-            resolution_proposed_at: 0,
-            resolved_at: 0,
-            finalized_at: 0,
-            resolver: Pubkey::default(),
-            proposed_outcome: None,
-            final_outcome: None,
-            ipfs_evidence_hash: [0; 46],
-            dispute_initiated_at: 0,
-            dispute_initiator: Pubkey::default(),
-            accumulated_protocol_fees: 0,
-            accumulated_resolver_fees: 0,
-            accumulated_lp_fees: 0,
             proposal_likes: likes,
             proposal_dislikes: dislikes,
             proposal_total_votes: total,
-            resolution_agree: 0,
-            resolution_disagree: 0,
-            resolution_total_votes: 0,
-            dispute_agree: 0,
-            dispute_disagree: 0,
-            dispute_total_votes: 0,
-            was_disputed: false,
-            is_cancelled: false,
-            cancelled_at: None,
-            bump: 255,
-            is_locked: false,
-            reserved: [0; 119],
+            ..MarketAccount::test_market()
         }
     }
 
@@ -1,8 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState, UserPosition};
-use crate::math::lmsr;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState, UserPosition, PAUSE_BUY};
 use crate::utils::calculate_fees_accurate;
 
 /// Minimum trade amount to prevent fee evasion through micro-trades
@@ -10,10 +9,11 @@ use crate::utils::calculate_fees_accurate;
 /// SECURITY: Finding #9 - Prevents micro-trade attacks that bypass fee mechanics
 pub const MIN_TRADE_AMOUNT: u64 = 10_000;
 
-/// Buy YES or NO shares using LMSR formula
+/// Buy YES or NO shares using the market's pricing curve
 ///
 /// Users specify a target cost (max they're willing to spend TOTAL) and receive
-/// shares calculated by the LMSR algorithm. Fees are included in the target cost:
+/// shares calculated by the market's curve (LMSR, LS-LMSR, or 1:1 stakes for
+/// parimutuel markets). Fees are included in the target cost:
 /// 3% protocol + 2% resolver + 5% LP = 10% total.
 ///
 /// # Arguments
@@ -21,13 +21,13 @@ pub const MIN_TRADE_AMOUNT: u64 = 10_000;
 /// * `target_cost` - Maximum amount user is willing to pay (TOTAL, including fees)
 ///
 /// # State Changes
-/// * Market: shares_yes/shares_no increased, liquidity increased by fees
-/// * Position: shares increased, total_invested increased, trades_count++
+/// * Market: shares_yes/shares_no increased, liquidity increased by cost and retained fees
+/// * Position: shares increased, total_invested and net_contribution increased, trades_count++
 ///
 /// # Fees
 /// * 3% → Protocol (transferred immediately)
 /// * 2% → Resolver (accumulated in market, paid on claim)
-/// * 5% → LP (accumulated in market, withdrawn by creator)
+/// * 5% → LP (accumulated in market, redeemed with LP shares)
 #[derive(Accounts)]
 pub struct BuyShares<'info> {
     #[account(
@@ -74,12 +74,14 @@ pub fn handler(
     outcome: bool,
     target_cost: u64,
 ) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
     let position = &mut ctx.accounts.position;
     let config = &ctx.accounts.global_config;
 
-    // Check if protocol is paused (emergency pause active)
-    require!(!config.is_paused, ErrorCode::ProtocolPaused);
+    // Check buying is not paused protocol-wide or for this market
+    config.require_not_paused(PAUSE_BUY)?;
+    market.require_not_frozen()?;
 
     // SECURITY FIX (Finding #9): Enforce minimum trade size
     // Prevents micro-trade attacks that evade fees or manipulate prices
@@ -100,11 +102,11 @@ pub fn handler(
         .checked_div(11000)
         .ok_or(ErrorCode::DivisionByZero)?;
 
-    // Calculate shares user gets for their adjusted target cost (using LMSR)
-    let (cost_before_fees, shares_bought) = lmsr::calculate_buy_cost(
+    // Calculate shares user gets for their adjusted target cost (using the market's curve)
+    let curve = market.pricing_curve();
+    let (cost_before_fees, shares_bought) = curve.buy_cost(
         market.shares_yes,
         market.shares_no,
-        market.b_parameter,
         outcome,
         max_cost_before_fees,
     )?;
@@ -112,11 +114,12 @@ pub fn handler(
     // SECURITY FIX (Finding #6): Use accurate fee calculation to prevent value leakage
     // Old approach calculated fees individually, losing precision on each division
     // New approach calculates total fees first, then splits proportionally
+    let fee_schedule = market.fee_schedule(config);
     let fees = calculate_fees_accurate(
         cost_before_fees,
-        config.protocol_fee_bps,
-        config.resolver_reward_bps,
-        config.liquidity_provider_fee_bps,
+        fee_schedule.protocol_fee_bps,
+        fee_schedule.resolver_reward_bps,
+        fee_schedule.liquidity_provider_fee_bps,
     )?;
 
     let total_cost = cost_before_fees
@@ -165,8 +168,11 @@ pub fn handler(
         .checked_add(total_cost)
         .ok_or(ErrorCode::OverflowError)?;
 
-    // Fees stay in market (resolver gets on claim, LP withdrawn by creator)
+    // Trade cost backs the shares; fees stay in market (resolver gets on
+    // claim, LP redeemed with LP shares)
     market.current_liquidity = market.current_liquidity
+        .checked_add(cost_before_fees)
+        .ok_or(ErrorCode::OverflowError)?
         .checked_add(fees.resolver_fee)
         .ok_or(ErrorCode::OverflowError)?
         .checked_add(fees.lp_fee)
@@ -190,9 +196,15 @@ pub fn handler(
         position.shares_yes = 0;
         position.shares_no = 0;
         position.total_invested = 0;
+        position.net_contribution = 0;
         position.trades_count = 0;
         position.has_claimed = false;
         position.bump = ctx.bumps.position;
+
+        // Track unsettled positions so close_market knows when claims are done
+        market.open_positions = market.open_positions
+            .checked_add(1)
+            .ok_or(ErrorCode::OverflowError)?;
     } else {
         // SECURITY: Validate ownership for existing accounts
         // This prevents account aliasing where attacker creates position with victim's address
@@ -226,6 +238,18 @@ pub fn handler(
         .ok_or(ErrorCode::OverflowError)?;
     position.last_trade_at = Clock::get()?.unix_timestamp;
 
+    // Net contribution tracks lamports that actually entered the market pool
+    // (protocol fee excluded), used as the INVALID refund basis
+    position.net_contribution = position.net_contribution
+        .checked_add(market_transfer)
+        .ok_or(ErrorCode::OverflowError)?;
+    market.total_net_contribution = market.total_net_contribution
+        .checked_add(market_transfer)
+        .ok_or(ErrorCode::OverflowError)?;
+
+    // SECURITY: Creator loss must stay within b * ln(2) after every trade
+    market.verify_bounded_loss()?;
+
     // Emit event (events defined in state.rs)
     emit!(SharesBought {
         market_id: market.market_id,
@@ -233,7 +257,7 @@ pub fn handler(
         outcome,
         shares: shares_bought,
         cost: total_cost,
-        new_price_yes: lmsr::calculate_yes_price(market.shares_yes, market.shares_no, market.b_parameter)?,
+        new_price_yes: curve.price(market.shares_yes, market.shares_no, true)?,
         timestamp: position.last_trade_at,
     });
 
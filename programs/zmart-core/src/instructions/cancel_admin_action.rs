@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, GlobalConfig, PendingAdminAction};
+use crate::error::ErrorCode;
+
+/// Close a pending admin action without executing it
+///
+/// The proposer may withdraw their action at any time. Anyone may close an
+/// action that can no longer execute: the signer set changed since it was
+/// proposed, or the multisig no longer holds admin. Rent always returns to
+/// the proposer.
+///
+/// # Errors
+///
+/// * `AdminActionActive` - If the action can still execute and the caller
+///   is not its proposer
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    /// Proposer, or anyone closing a stale action
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin-multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    /// CHECK: Receives the pending action rent, validated by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// Handler for cancel_admin_action instruction
+pub fn handler(ctx: Context<CancelAdminAction>) -> Result<()> {
+    let pending_action = &ctx.accounts.pending_action;
+    let caller = ctx.accounts.caller.key();
+
+    let stale = pending_action.is_stale(
+        &ctx.accounts.multisig,
+        &ctx.accounts.multisig.key(),
+        &ctx.accounts.global_config.admin,
+    );
+    require!(
+        stale || caller == pending_action.proposer,
+        ErrorCode::AdminActionActive
+    );
+
+    emit!(AdminActionCancelled {
+        pending_action: pending_action.key(),
+        cancelled_by: caller,
+        stale,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a pending admin action is closed without executing
+#[event]
+pub struct AdminActionCancelled {
+    pub pending_action: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub stale: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, PendingConfig};
+use crate::error::ErrorCode;
+
+/// Cancel a queued config change before it is applied
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+/// * `NoPendingConfig` - If no change is queued
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    /// Protocol admin with configuration authority
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Queued config change
+    #[account(
+        mut,
+        seeds = [b"pending-config"],
+        bump = pending_config.bump,
+        constraint = pending_config.is_pending @ ErrorCode::NoPendingConfig
+    )]
+    pub pending_config: Account<'info, PendingConfig>,
+}
+
+/// Handler for cancel_config_update instruction
+pub fn handler(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_config;
+    pending.is_pending = false;
+
+    emit!(ConfigUpdateCancelled {
+        cancelled_by: ctx.accounts.admin.key(),
+        effective_at: pending.effective_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Queued global config update cancelled");
+
+    Ok(())
+}
+
+/// Event emitted when a queued config change is cancelled
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub cancelled_by: Pubkey,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
@@ -5,16 +5,19 @@ use crate::error::ErrorCode;
 /// Cancel a market and transition to terminal CANCELLED state
 ///
 /// Allows protocol admin to cancel markets that are invalid, fraudulent, or
-/// require refunding users. Only works for markets in PROPOSED or APPROVED states.
-/// Active, resolving, or finalized markets cannot be cancelled.
+/// require refunding users. Works for any non-terminal state (PROPOSED, APPROVED,
+/// ACTIVE, RESOLVING, DISPUTED), e.g. when a question turns out to be broken
+/// after trading has started. Finalized markets cannot be cancelled.
 ///
 /// This instruction sets the market state to CANCELLED. Actual refunds are handled
-/// by a separate cancel_market_refund instruction to avoid compute unit limits.
+/// per position by claim_cancellation_refund to avoid compute unit limits; the
+/// creator's remaining subsidy is returned through withdraw_liquidity, and
+/// third-party LPs' through remove_liquidity.
 ///
 /// # Errors
 ///
 /// * `Unauthorized` - If signer is not admin
-/// * `CannotCancelMarket` - If market not in PROPOSED or APPROVED state
+/// * `CannotCancelMarket` - If market is FINALIZED
 /// * `MarketAlreadyCancelled` - If market already in CANCELLED state
 #[derive(Accounts)]
 pub struct CancelMarket<'info> {
@@ -48,15 +51,15 @@ pub fn handler(ctx: Context<CancelMarket>) -> Result<()> {
         ErrorCode::MarketAlreadyCancelled
     );
 
-    // Only allow cancellation of PROPOSED or APPROVED markets
-    // Active, resolving, disputed, or finalized markets cannot be cancelled
+    // Finalized markets already pay out through claims and cannot be cancelled
     require!(
-        market.state == MarketState::Proposed || market.state == MarketState::Approved,
+        market.can_transition_to(MarketState::Cancelled),
         ErrorCode::CannotCancelMarket
     );
 
-    // Transition to CANCELLED state (terminal state)
-    market.state = MarketState::Cancelled;
+    // Transition to CANCELLED state (terminal state, using wrapper for validation)
+    market.transition_state(MarketState::Cancelled)?;
+    market.is_cancelled = true;
 
     // Record cancellation timestamp
     let clock = Clock::get()?;
@@ -94,44 +97,13 @@ mod tests {
     fn create_test_market(state: MarketState) -> MarketAccount {
         MarketAccount {
             market_id: [1; 32],
-            creator: Pubkey::new_unique(),
             state,
             b_parameter: 1_000_000_000, // 1 SOL in fixed-point
             initial_liquidity: 100_000_000,
             current_liquidity: 100_000_000,
-            shares_yes: 0,
-            shares_no: 0,
-            total_volume: 0,
-            created_at: 0,
-            approved_at: 0,
-            activated_at: 0,
-            resolution_proposed_at: 0,
-            resolved_at: 0,
-            finalized_at: 0,
             resolver: Pubkey::new_unique(),
-            proposed_outcome: None,
-            final_outcome: None,
-            ipfs_evidence_hash: [0; 46],
-            dispute_initiated_at: 0,
             dispute_initiator: Pubkey::new_unique(),
-            accumulated_protocol_fees: 0,
-            accumulated_resolver_fees: 0,
-            accumulated_lp_fees: 0,
-            proposal_likes: 0,
-            proposal_dislikes: 0,
-            proposal_total_votes: 0,
-            resolution_agree: 0,
-            resolution_disagree: 0,
-            resolution_total_votes: 0,
-            dispute_agree: 0,
-            dispute_disagree: 0,
-            dispute_total_votes: 0,
-            was_disputed: false,
-            is_cancelled: false,
-            cancelled_at: None,
-            is_locked: false,
-            reserved: [0; 119],
-            bump: 255,
+            ..MarketAccount::test_market()
         }
     }
 
@@ -160,33 +132,29 @@ mod tests {
     }
 
     #[test]
-    fn test_cannot_cancel_active_market() {
-        // Market in ACTIVE state
-        let market = create_test_market(MarketState::Active);
+    fn test_cancel_active_market() {
+        // Market in ACTIVE state (trading started, question broken)
+        let mut market = create_test_market(MarketState::Active);
 
-        // Should fail to cancel (would require error in actual instruction)
-        assert_ne!(market.state, MarketState::Proposed);
-        assert_ne!(market.state, MarketState::Approved);
+        assert!(market.can_transition_to(MarketState::Cancelled));
+        market.transition_state(MarketState::Cancelled).unwrap();
+        assert_eq!(market.state, MarketState::Cancelled);
     }
 
     #[test]
-    fn test_cannot_cancel_resolving_market() {
+    fn test_cancel_resolving_market() {
         // Market in RESOLVING state
         let market = create_test_market(MarketState::Resolving);
 
-        // Should fail to cancel
-        assert_ne!(market.state, MarketState::Proposed);
-        assert_ne!(market.state, MarketState::Approved);
+        assert!(market.can_transition_to(MarketState::Cancelled));
     }
 
     #[test]
-    fn test_cannot_cancel_disputed_market() {
+    fn test_cancel_disputed_market() {
         // Market in DISPUTED state
         let market = create_test_market(MarketState::Disputed);
 
-        // Should fail to cancel
-        assert_ne!(market.state, MarketState::Proposed);
-        assert_ne!(market.state, MarketState::Approved);
+        assert!(market.can_transition_to(MarketState::Cancelled));
     }
 
     #[test]
@@ -195,8 +163,7 @@ mod tests {
         let market = create_test_market(MarketState::Finalized);
 
         // Should fail to cancel
-        assert_ne!(market.state, MarketState::Proposed);
-        assert_ne!(market.state, MarketState::Approved);
+        assert!(!market.can_transition_to(MarketState::Cancelled));
     }
 
     #[test]
@@ -228,6 +195,7 @@ mod tests {
         assert_eq!(MarketState::Disputed as u8, 4);
         assert_eq!(MarketState::Finalized as u8, 5);
         assert_eq!(MarketState::Cancelled as u8, 6);
+        assert_eq!(MarketState::Rejected as u8, 7);
     }
 
     #[test]
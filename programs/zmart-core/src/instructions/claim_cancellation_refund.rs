@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState, UserPosition, PAUSE_CLAIM};
+use crate::utils::{max_transferable_amount, transfer_from_pda_with_data};
+
+/// Claim refund after market cancelled
+///
+/// Each trader recovers their net contribution (amount paid in minus amount
+/// received from sells) from a CANCELLED market. Refunds are pro-rata over the
+/// lamports the market can pay out, capped at the position's net contribution,
+/// so the market can never be drained below what later claimers are owed.
+/// Markets created before net contributions were tracked refund each
+/// position's `total_invested` instead, while the market can pay.
+///
+/// # Errors
+///
+/// * `InvalidMarketState` - If market is not CANCELLED
+/// * `AlreadyClaimed` - If position already refunded
+/// * `NoWinnings` - If position has no net contribution to refund
+#[derive(Accounts)]
+pub struct ClaimCancellationRefund<'info> {
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump,
+        constraint = market.state == MarketState::Cancelled @ ErrorCode::InvalidMarketState
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        has_one = user,
+        constraint = !position.has_claimed @ ErrorCode::AlreadyClaimed
+    )]
+    pub position: Account<'info, UserPosition>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Global configuration (pause flags)
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<ClaimCancellationRefund>) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let position = &mut ctx.accounts.position;
+
+    ctx.accounts.global_config.require_not_paused(PAUSE_CLAIM)?;
+
+    // Lamports the market can pay out while staying rent-exempt
+    let available = max_transferable_amount(&market.to_account_info())?;
+    let refund = market.cancellation_refund(position, available)?;
+
+    // SECURITY FIX (Finding #2): Transfer with rent check
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        refund,
+    )?;
+
+    market.release_refund(position)?;
+
+    position.has_claimed = true;
+    position.claimed_amount = refund;
+    market.settle_position();
+
+    emit!(CancellationRefundClaimed {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount: refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CancellationRefundClaimed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RefundAccounting;
+
+    fn create_test_market(total_net_contribution: u64) -> MarketAccount {
+        MarketAccount {
+            state: MarketState::Cancelled,
+            total_net_contribution,
+            ..MarketAccount::test_market()
+        }
+    }
+
+    fn create_test_position(net_contribution: u64) -> UserPosition {
+        UserPosition {
+            total_invested: net_contribution,
+            trades_count: 1,
+            net_contribution,
+            ..UserPosition::test_position()
+        }
+    }
+
+    #[test]
+    fn test_refund_requires_cancelled_market() {
+        let position = create_test_position(3_000_000);
+        let mut market = create_test_market(5_000_000);
+
+        for state in [MarketState::Active, MarketState::Finalized, MarketState::Rejected] {
+            market.state = state;
+            assert_eq!(
+                market.cancellation_refund(&position, 9_000_000).unwrap_err(),
+                error!(ErrorCode::InvalidMarketState)
+            );
+        }
+
+        market.state = MarketState::Cancelled;
+        market.is_frozen = true;
+        assert_eq!(
+            market.cancellation_refund(&position, 9_000_000).unwrap_err(),
+            error!(ErrorCode::MarketPaused)
+        );
+
+        market.is_frozen = false;
+        assert_eq!(
+            market.cancellation_refund(&create_test_position(0), 9_000_000).unwrap_err(),
+            error!(ErrorCode::NoWinnings)
+        );
+    }
+
+    #[test]
+    fn test_refunds_capped_after_release() {
+        // Subsidy on top of contributions: everyone is made whole, never more
+        let a = create_test_position(3_000_000);
+        let b = create_test_position(2_000_000);
+        let mut market = create_test_market(5_000_000);
+        let mut available = 9_000_000;
+
+        for position in [&a, &b] {
+            let refund = market.cancellation_refund(position, available).unwrap();
+            assert_eq!(refund, position.net_contribution);
+            available -= refund;
+            market.release_refund(position).unwrap();
+        }
+
+        assert_eq!(market.total_net_contribution, 0);
+        assert_eq!(market.outstanding_refunds(), 0);
+        assert_eq!(available, 4_000_000); // Left for the LPs
+    }
+
+    #[test]
+    fn test_legacy_market_reserves_refunds() {
+        // Cancelled market created before net contributions were tracked
+        let position = UserPosition {
+            total_invested: 3_000_000,
+            trades_count: 2,
+            ..UserPosition::test_position()
+        };
+        let mut market = MarketAccount {
+            refund_accounting: RefundAccounting::Legacy,
+            total_volume: 4_000_000,
+            ..create_test_market(0)
+        };
+
+        // The creator cannot withdraw what traders may still claim
+        assert_eq!(market.lp_withdrawable(5_000_000, 0), 990_000);
+
+        assert_eq!(market.cancellation_refund(&position, 5_000_000).unwrap(), 3_000_000);
+        market.release_refund(&position).unwrap();
+        assert_eq!(market.outstanding_refunds(), 1_000_000);
+    }
+}
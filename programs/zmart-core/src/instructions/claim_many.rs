@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+use solana_program::compute_units::sol_remaining_compute_units;
+use crate::error::ErrorCode;
+use crate::instructions::claim_winnings::claim_position;
+use crate::state::{GlobalConfig, MarketAccount, MarketState, UserPosition, PAUSE_CLAIM};
+
+/// Compute units kept in reserve before starting another claim
+///
+/// One claim (two deserializations, PDA checks, transfer, event, write-back)
+/// stays well below this, so the batch never fails mid-claim.
+pub const CLAIM_COMPUTE_RESERVE: u64 = 30_000;
+
+/// Claim winnings from many finalized markets in one transaction
+///
+/// Remaining accounts are (market, position) pairs, both writable. Each pair
+/// is validated like claim_winnings (canonical PDAs, FINALIZED market,
+/// position owned by `user`, not yet claimed) and paid out with the same
+/// rules, emitting one WinningsClaimed event per market.
+///
+/// Processing stops cleanly once fewer than `CLAIM_COMPUTE_RESERVE` compute
+/// units remain; pairs already processed stay claimed and the caller can
+/// resubmit the rest.
+///
+/// # Errors
+///
+/// * `InvalidClaimAccounts` - If remaining accounts are not (market, position) pairs
+/// * Any error from claim_winnings for an individual pair
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// System program for CPI transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts must be a non-empty list of (market, position) pairs
+// `usize::is_multiple_of` needs Rust 1.87, newer than the SBF toolchain
+#[allow(clippy::manual_is_multiple_of)]
+fn is_valid_pair_count(len: usize) -> bool {
+    len != 0 && len % 2 == 0
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        is_valid_pair_count(remaining.len()),
+        ErrorCode::InvalidClaimAccounts
+    );
+
+    ctx.accounts.global_config.require_not_paused(PAUSE_CLAIM)?;
+    let claim_period = ctx.accounts.global_config.claim_window();
+    let user = ctx.accounts.user.to_account_info();
+
+    let claimed = claim_pairs(ctx.program_id, &user, remaining, claim_period)?;
+
+    msg!("claim_many: claimed {} of {} positions", claimed, remaining.len() / 2);
+
+    Ok(())
+}
+
+/// Claim (market, position) pairs in order until compute runs low
+///
+/// Returns the number of pairs claimed.
+fn claim_pairs<'info>(
+    program_id: &Pubkey,
+    user: &AccountInfo<'info>,
+    remaining: &'info [AccountInfo<'info>],
+    claim_period: i64,
+) -> Result<usize> {
+    let mut claimed = 0usize;
+
+    for pair in remaining.chunks_exact(2) {
+        if sol_remaining_compute_units() < CLAIM_COMPUTE_RESERVE {
+            break;
+        }
+
+        let (market_info, position_info) = (&pair[0], &pair[1]);
+        require!(
+            market_info.is_writable && position_info.is_writable,
+            ErrorCode::InvalidClaimAccounts
+        );
+
+        // Owner + discriminator checks
+        let mut market: Account<'info, MarketAccount> = Account::try_from(market_info)?;
+        let mut position: Account<'info, UserPosition> = Account::try_from(position_info)?;
+
+        // Same constraints as ClaimWinnings, checked manually
+        let market_pda = Pubkey::create_program_address(
+            &[b"market", &market.market_id, &[market.bump]],
+            program_id,
+        ).map_err(|_| ErrorCode::InvalidClaimAccounts)?;
+        let position_pda = Pubkey::create_program_address(
+            &[b"position", market.key().as_ref(), user.key.as_ref(), &[position.bump]],
+            program_id,
+        ).map_err(|_| ErrorCode::InvalidClaimAccounts)?;
+
+        require_keys_eq!(market.key(), market_pda, ErrorCode::InvalidClaimAccounts);
+        require_keys_eq!(position.key(), position_pda, ErrorCode::InvalidClaimAccounts);
+        require_keys_eq!(position.user, user.key(), ErrorCode::Unauthorized);
+        require!(
+            market.state == MarketState::Finalized,
+            ErrorCode::InvalidMarketState
+        );
+        require!(!position.has_claimed, ErrorCode::AlreadyClaimed);
+
+        claim_position(&mut market, &mut position, user, claim_period)?;
+
+        // Persist before the next pair so duplicates see the claimed state
+        market.exit(program_id)?;
+        position.exit(program_id)?;
+
+        claimed += 1;
+    }
+
+    Ok(claimed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MarketOutcome, MarketAccount, UserPosition};
+    use solana_program::clock::Clock;
+    use solana_program::entrypoint::SUCCESS;
+    use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use solana_program::rent::Rent;
+    use std::cell::Cell;
+    use std::sync::Once;
+
+    /// Compute units one claim is charged by `TestSyscalls`
+    const CLAIM_COST: u64 = 40_000;
+
+    thread_local! {
+        /// Compute units left for the claim loop running on this thread
+        static COMPUTE_LEFT: Cell<u64> = const { Cell::new(0) };
+    }
+
+    /// Syscalls the claim path needs off-chain: clock, rent and a compute
+    /// meter that charges `CLAIM_COST` per check
+    struct TestSyscalls;
+
+    impl SyscallStubs for TestSyscalls {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { unix_timestamp: 1_000, ..Clock::default() };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            SUCCESS
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            SUCCESS
+        }
+
+        fn sol_remaining_compute_units(&self) -> u64 {
+            COMPUTE_LEFT.with(|left| {
+                let remaining = left.get();
+                left.set(remaining.saturating_sub(CLAIM_COST));
+                remaining
+            })
+        }
+    }
+
+    fn install_syscalls(compute_units: u64) {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            set_syscall_stubs(Box::new(TestSyscalls));
+        });
+        COMPUTE_LEFT.with(|left| left.set(compute_units));
+    }
+
+    fn account_info(
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    ) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(lamports)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    /// Finalized YES market holding `pool` lamports, and `user`'s position
+    /// owning half of the winning shares
+    fn claim_pair(seed: u8, user: &Pubkey, pool: u64) -> [AccountInfo<'static>; 2] {
+        let market_id = [seed; 32];
+        let (market_key, market_bump) =
+            Pubkey::find_program_address(&[b"market", &market_id], &crate::ID);
+        let (position_key, position_bump) = Pubkey::find_program_address(
+            &[b"position", market_key.as_ref(), user.as_ref()],
+            &crate::ID,
+        );
+
+        let market = MarketAccount {
+            market_id,
+            state: MarketState::Finalized,
+            final_outcome: Some(MarketOutcome::Yes),
+            shares_yes: 100,
+            current_liquidity: pool,
+            open_positions: 1,
+            bump: market_bump,
+            ..MarketAccount::test_market()
+        };
+        let position = UserPosition {
+            market: market_key,
+            user: *user,
+            shares_yes: 50,
+            bump: position_bump,
+            ..UserPosition::test_position()
+        };
+
+        let mut market_data = Vec::new();
+        market.try_serialize(&mut market_data).unwrap();
+        market_data.resize(8 + MarketAccount::LEN, 0);
+        let mut position_data = Vec::new();
+        position.try_serialize(&mut position_data).unwrap();
+        position_data.resize(UserPosition::LEN, 0);
+
+        let rent = Rent::default();
+        [
+            account_info(
+                market_key,
+                crate::ID,
+                rent.minimum_balance(market_data.len()) + pool,
+                market_data,
+            ),
+            account_info(
+                position_key,
+                crate::ID,
+                rent.minimum_balance(position_data.len()),
+                position_data,
+            ),
+        ]
+    }
+
+    fn user_info(user: Pubkey) -> AccountInfo<'static> {
+        account_info(user, anchor_lang::system_program::ID, 0, Vec::new())
+    }
+
+    fn has_claimed(position_info: &AccountInfo) -> bool {
+        let data = position_info.try_borrow_data().unwrap();
+        UserPosition::try_deserialize(&mut &data[..]).unwrap().has_claimed
+    }
+
+    fn leak(accounts: Vec<AccountInfo<'static>>) -> &'static [AccountInfo<'static>] {
+        Box::leak(accounts.into_boxed_slice())
+    }
+
+    #[test]
+    fn test_pairs_required() {
+        // Remaining accounts must be non-empty (market, position) pairs
+        for (len, valid) in [(0usize, false), (1, false), (2, true), (5, false), (20, true)] {
+            assert_eq!(is_valid_pair_count(len), valid);
+        }
+    }
+
+    #[test]
+    fn test_claims_every_pair() {
+        let user = Pubkey::new_unique();
+        let user_info = user_info(user);
+        let [market_a, position_a] = claim_pair(1, &user, 1_000_000);
+        let [market_b, position_b] = claim_pair(2, &user, 3_000_000);
+        let remaining = leak(vec![market_a, position_a, market_b, position_b]);
+
+        install_syscalls(CLAIM_COMPUTE_RESERVE + 2 * CLAIM_COST);
+        let claimed = claim_pairs(&crate::ID, &user_info, remaining, 86_400).unwrap();
+
+        assert_eq!(claimed, 2);
+        assert_eq!(user_info.lamports(), 500_000 + 1_500_000);
+        assert!(has_claimed(&remaining[1]));
+        assert!(has_claimed(&remaining[3]));
+    }
+
+    #[test]
+    fn test_duplicate_pair_sees_claimed_position() {
+        // The same pair twice: the second pass reads what exit() wrote back
+        let user = Pubkey::new_unique();
+        let user_info = user_info(user);
+        let [market, position] = claim_pair(3, &user, 1_000_000);
+        let remaining = leak(vec![market.clone(), position.clone(), market, position]);
+
+        install_syscalls(CLAIM_COMPUTE_RESERVE + 2 * CLAIM_COST);
+        let result = claim_pairs(&crate::ID, &user_info, remaining, 86_400);
+
+        assert_eq!(result.unwrap_err(), error!(ErrorCode::AlreadyClaimed));
+        assert!(has_claimed(&remaining[1]));
+        assert_eq!(user_info.lamports(), 500_000); // Paid once
+    }
+
+    #[test]
+    fn test_stops_when_compute_runs_low() {
+        let user = Pubkey::new_unique();
+        let user_info = user_info(user);
+        let [market_a, position_a] = claim_pair(4, &user, 1_000_000);
+        let [market_b, position_b] = claim_pair(5, &user, 1_000_000);
+        let [market_c, position_c] = claim_pair(6, &user, 1_000_000);
+        let remaining = leak(vec![
+            market_a, position_a, market_b, position_b, market_c, position_c,
+        ]);
+
+        // Budget for two claims; the third check falls below the reserve
+        install_syscalls(CLAIM_COMPUTE_RESERVE + 2 * CLAIM_COST - 1);
+        let claimed = claim_pairs(&crate::ID, &user_info, remaining, 86_400).unwrap();
+
+        assert_eq!(claimed, 2);
+        assert!(has_claimed(&remaining[1]));
+        assert!(has_claimed(&remaining[3]));
+        assert!(!has_claimed(&remaining[5])); // Left for a resubmission
+        assert_eq!(user_info.lamports(), 1_000_000);
+
+        // Out of compute before the first pair: nothing claimed, no error
+        install_syscalls(CLAIM_COMPUTE_RESERVE - 1);
+        assert_eq!(claim_pairs(&crate::ID, &user_info, &remaining[4..], 86_400).unwrap(), 0);
+        assert!(!has_claimed(&remaining[5]));
+    }
+}
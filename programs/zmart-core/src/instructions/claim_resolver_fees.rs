@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState, PAUSE_CLAIM};
+use crate::utils::transfer_from_pda_with_data;
+
+/// Claim accumulated resolver fees after market finalized
+///
+/// The resolver withdraws the 2% resolver pool in a single transaction,
+/// independently of trader claims.
+///
+/// - YES/NO outcome: resolver receives `accumulated_resolver_fees`
+/// - INVALID outcome: resolver pool is forfeited and stays in the market,
+///   where it is returned to traders through their INVALID refunds
+///
+/// # Errors
+///
+/// * `InvalidResolver` - If signer is not `market.resolver`
+/// * `ResolverFeesAlreadyClaimed` - If fees were already withdrawn
+/// * `NoResolverFees` - If nothing accumulated or outcome is INVALID
+/// * `ClaimPeriodEnded` - If the claim window after finalization has expired
+#[derive(Accounts)]
+pub struct ClaimResolverFees<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump,
+        constraint = market.state == MarketState::Finalized @ ErrorCode::InvalidMarketState,
+        constraint = !market.resolver_fees_claimed @ ErrorCode::ResolverFeesAlreadyClaimed
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    /// Resolver who proposed the final outcome
+    #[account(
+        mut,
+        constraint = resolver.key() == market.resolver @ ErrorCode::InvalidResolver
+    )]
+    pub resolver: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimResolverFees>) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+
+    ctx.accounts.global_config.require_not_paused(PAUSE_CLAIM)?;
+
+    // INVALID outcome (or empty pool) yields nothing to claim
+    let amount = market.claimable_resolver_fees(
+        ctx.accounts.global_config.claim_window(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // SECURITY FIX (Finding #2): Transfer with rent check
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.resolver.to_account_info(),
+        amount,
+    )?;
+
+    // accumulated_resolver_fees is left untouched so the winners' payout
+    // pool (current_liquidity - accumulated_resolver_fees) stays stable
+    market.resolver_fees_claimed = true;
+
+    emit!(ResolverFeesClaimed {
+        market: market.key(),
+        resolver: ctx.accounts.resolver.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolverFeesClaimed {
+    pub market: Pubkey,
+    pub resolver: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MarketOutcome;
+
+    const CLAIM_PERIOD: i64 = 1_000;
+
+    fn create_test_market(final_outcome: MarketOutcome) -> MarketAccount {
+        MarketAccount {
+            state: MarketState::Finalized,
+            current_liquidity: 1_000_000,
+            finalized_at: 100,
+            resolver: Pubkey::new_unique(),
+            proposed_outcome: Some(final_outcome),
+            final_outcome: Some(final_outcome),
+            accumulated_resolver_fees: 20_000,
+            ..MarketAccount::test_market()
+        }
+    }
+
+    #[test]
+    fn test_winner_pool_excludes_resolver_fees() {
+        let mut market = create_test_market(MarketOutcome::Yes);
+        let pool = market.winners_pool();
+
+        assert_eq!(market.claimable_resolver_fees(CLAIM_PERIOD, 200).unwrap(), 20_000);
+        assert_eq!(pool, 980_000);
+        assert_eq!(pool + market.unclaimed_resolver_fees(), market.current_liquidity);
+
+        // Claiming leaves the winners' pool where it was
+        market.resolver_fees_claimed = true;
+        assert_eq!(market.unclaimed_resolver_fees(), 0);
+        assert_eq!(market.winners_pool(), pool);
+    }
+
+    #[test]
+    fn test_invalid_outcome_keeps_pool_for_refunds() {
+        let market = create_test_market(MarketOutcome::Invalid);
+
+        // Forfeited, refunded to traders
+        assert_eq!(market.unclaimed_resolver_fees(), 0);
+        assert_eq!(
+            market.claimable_resolver_fees(CLAIM_PERIOD, 200).unwrap_err(),
+            error!(ErrorCode::NoResolverFees)
+        );
+    }
+
+    #[test]
+    fn test_claim_rejected_when_frozen_or_expired() {
+        let mut market = create_test_market(MarketOutcome::No);
+
+        assert_eq!(
+            market.claimable_resolver_fees(CLAIM_PERIOD, 100 + CLAIM_PERIOD).unwrap_err(),
+            error!(ErrorCode::ClaimPeriodEnded)
+        );
+
+        market.is_frozen = true;
+        assert_eq!(
+            market.claimable_resolver_fees(CLAIM_PERIOD, 200).unwrap_err(),
+            error!(ErrorCode::MarketPaused)
+        );
+    }
+}
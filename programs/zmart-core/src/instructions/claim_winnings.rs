@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState, UserPosition};
-use crate::utils::transfer_from_pda_with_data;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketOutcome, MarketState, UserPosition, PAUSE_CLAIM};
+use crate::utils::{max_transferable_amount, transfer_from_pda_with_data};
 
 /// Claim winnings after market finalized
 ///
 /// Users claim winnings based on final outcome:
 /// - YES outcome: Only YES share holders win
 /// - NO outcome: Only NO share holders win
-/// - INVALID outcome: All holders refunded proportionally
+/// - INVALID outcome: All holders refunded pro-rata by net contribution
 ///
-/// First claimer pays resolver their accumulated fees (if outcome valid)
+/// The resolver pool is excluded from the winners' payout pool and is paid
+/// separately via claim_resolver_fees.
+///
+/// Claims must be made before the market's `claim_deadline`, fixed from
+/// `GlobalConfig::claim_period` at finalization; afterwards unclaimed funds can be swept via sweep_unclaimed.
 #[derive(Accounts)]
 pub struct ClaimWinnings<'info> {
     #[account(
@@ -39,75 +43,96 @@ pub struct ClaimWinnings<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// Resolver (receives accumulated fees if outcome valid)
-    /// CHECK: Validated against market.resolver
-    #[account(
-        mut,
-        constraint = resolver.key() == market.resolver @ ErrorCode::InvalidResolver
-    )]
-    pub resolver: AccountInfo<'info>,
-
     /// System program for CPI transfers
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
-    let market = &mut ctx.accounts.market;
-    let position = &mut ctx.accounts.position;
+    ctx.accounts.global_config.require_not_paused(PAUSE_CLAIM)?;
+    let claim_period = ctx.accounts.global_config.claim_window();
+
+    claim_position(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.position,
+        &ctx.accounts.user.to_account_info(),
+        claim_period,
+    )
+}
+
+/// Pay out a single position according to the market's final outcome
+///
+/// Shared by claim_winnings and claim_many. Callers must have verified that
+/// the market is FINALIZED, the position belongs to `user` and this market,
+/// and the position has not been claimed yet.
+pub(crate) fn claim_position<'info>(
+    market: &mut Account<'info, MarketAccount>,
+    position: &mut Account<'info, UserPosition>,
+    user: &AccountInfo<'info>,
+    claim_period: i64,
+) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(market)?;
+    market.require_not_frozen()?;
 
     // BLUEPRINT PAYOUT FORMULA (CORE_LOGIC_INVARIANTS.md Section 8):
     // user_payout = (user_winning_shares / total_winning_shares) * totalDeposits
     //
     // Where:
-    // - totalDeposits = current_liquidity (all deposits minus fees already distributed)
+    // - totalDeposits = market.winners_pool(): current_liquidity minus the resolver pool
+    //   (paid via claim_resolver_fees) and LP fees (redeemed with LP shares)
     // - total_winning_shares = market.shares_yes (if YES won) or market.shares_no (if NO won)
     // - user_winning_shares = position.shares_yes (if YES won) or position.shares_no (if NO won)
 
-    let (user_winning_shares, total_winning_shares) = match market.final_outcome {
-        Some(true) => {
+    let final_outcome = market.final_outcome.ok_or(ErrorCode::InvalidMarketState)?;
+
+    // Claims close after the configured window; leftovers are swept to the treasury
+    require!(
+        !market.claim_period_ended(claim_period, Clock::get()?.unix_timestamp),
+        ErrorCode::ClaimPeriodEnded
+    );
+
+    let (user_winning_shares, total_winning_shares) = match final_outcome {
+        MarketOutcome::Yes => {
             // YES outcome won
             require!(position.shares_yes > 0, ErrorCode::NoWinnings);
             (position.shares_yes, market.shares_yes)
         },
-        Some(false) => {
+        MarketOutcome::No => {
             // NO outcome won
             require!(position.shares_no > 0, ErrorCode::NoWinnings);
             (position.shares_no, market.shares_no)
         },
-        None => {
-            // INVALID outcome → pro-rata refund based on total invested
-            // Formula: user_refund = (user.totalInvested / market.totalDeposits) * market.totalDeposits
-            // Simplifies to: user_refund = user.totalInvested
-            require!(position.total_invested > 0, ErrorCode::NoWinnings);
-
-            // For INVALID, we just return what the user invested (no proportional calc needed)
-            let winnings = position.total_invested;
+        MarketOutcome::Invalid => {
+            // INVALID outcome → pro-rata refund based on net contribution
+            // Formula: user_refund = (user.net_contribution / market.total_net_contribution) * available
+            // capped at user.net_contribution. Sold shares already returned lamports to
+            // the user, so only the net amount left in the pool is refundable.
+            // Legacy markets refund total_invested instead (see refund_owed).
+            // (resolver pool is forfeited and funds these refunds)
 
-            // Check market has sufficient balance
-            let market_balance = market.to_account_info().lamports();
-            require!(
-                market_balance >= winnings,
-                ErrorCode::InsufficientLiquidity
-            );
+            // Lamports the market can pay out while staying rent-exempt
+            let available = max_transferable_amount(&market.to_account_info())?;
+            let winnings = market.refund_owed(position, available)?;
 
-            // Early return for INVALID case
-            market.lock()?;
+            require!(winnings > 0, ErrorCode::NoWinnings);
 
             // Transfer from market PDA (PDAs with data require manual lamport transfer)
             transfer_from_pda_with_data(
                 &market.to_account_info(),
-                &ctx.accounts.user.to_account_info(),
+                user,
                 winnings,
             )?;
 
-            market.unlock();
+            market.release_refund(position)?;
+
             position.has_claimed = true;
             position.claimed_amount = winnings;
+            market.settle_position();
 
             emit!(WinningsClaimed {
                 market: market.key(),
-                user: ctx.accounts.user.key(),
-                outcome: None,
+                user: user.key(),
+                outcome: MarketOutcome::Invalid,
                 amount: winnings,
                 timestamp: Clock::get()?.unix_timestamp,
             });
@@ -120,8 +145,12 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     // Formula: (user_shares / total_shares) * total_deposits
     require!(total_winning_shares > 0, ErrorCode::DivisionByZero);
 
+    // Resolver pool is reserved for claim_resolver_fees and LP fees for LPs.
+    // Neither is zeroed before winners are settled, so this basis is identical
+    // for every claimer.
+    let total_deposits = market.winners_pool();
+
     // Use 128-bit arithmetic to prevent overflow in multiplication
-    let total_deposits = market.current_liquidity;
     let winnings = (user_winning_shares as u128)
         .checked_mul(total_deposits as u128)
         .ok_or(ErrorCode::OverflowError)?
@@ -130,9 +159,9 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
 
     require!(winnings > 0, ErrorCode::NoWinnings);
 
-    // Check market has sufficient balance
+    // Check market has sufficient balance (resolver must remain payable)
     let market_balance = market.to_account_info().lamports();
-    let needed = winnings.checked_add(market.accumulated_resolver_fees)
+    let needed = winnings.checked_add(market.unclaimed_resolver_fees())
         .ok_or(ErrorCode::OverflowError)?;
 
     require!(
@@ -140,44 +169,25 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
         ErrorCode::InsufficientLiquidity
     );
 
-    // SECURITY FIX (Finding #8): Lock market before lamport transfers (reentrancy protection)
-    market.lock()?;
-
     // SECURITY FIX (Finding #2): Transfer winnings with rent check
     // Ensures market account maintains rent exemption after transfer
     // Uses manual lamport transfer since market is a PDA with data
     transfer_from_pda_with_data(
         &market.to_account_info(),
-        &ctx.accounts.user.to_account_info(),
+        user,
         winnings,
     )?;
 
-    // SECURITY FIX (Finding #2): Pay resolver with rent check
-    // Only if outcome is valid and fees accumulated
-    if market.final_outcome.is_some() && market.accumulated_resolver_fees > 0 {
-        let resolver_fee = market.accumulated_resolver_fees;
-
-        transfer_from_pda_with_data(
-            &market.to_account_info(),
-            &ctx.accounts.resolver,
-            resolver_fee,
-        )?;
-
-        market.accumulated_resolver_fees = 0; // Paid out, prevent double-pay
-    }
-
-    // SECURITY FIX (Finding #8): Unlock market after transfers complete
-    market.unlock();
-
     // Mark position as claimed
     position.has_claimed = true;
     position.claimed_amount = winnings;
+    market.settle_position();
 
     // Emit event
     emit!(WinningsClaimed {
         market: market.key(),
-        user: ctx.accounts.user.key(),
-        outcome: market.final_outcome,
+        user: user.key(),
+        outcome: final_outcome,
         amount: winnings,
         timestamp: Clock::get()?.unix_timestamp,
     });
@@ -189,7 +199,7 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
 pub struct WinningsClaimed {
     pub market: Pubkey,
     pub user: Pubkey,
-    pub outcome: Option<bool>,
+    pub outcome: MarketOutcome,
     pub amount: u64,
     pub timestamp: i64,
 }
@@ -197,6 +207,7 @@ pub struct WinningsClaimed {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::RefundAccounting;
 
     #[test]
     fn test_winnings_yes_outcome() {
@@ -232,18 +243,65 @@ mod tests {
 
     #[test]
     fn test_winnings_invalid_outcome() {
-        // INVALID: full refund (all shares)
-        let shares_yes = 100u64;
-        let shares_no = 50u64;
-        let outcome: Option<bool> = None;
+        // INVALID: refund net contribution, not gross amount invested
+        let position = UserPosition {
+            shares_yes: 500,
+            total_invested: 1_100, // Paid into the pool
+            trades_count: 2,
+            net_contribution: 200, // 900 taken back out by selling
+            ..UserPosition::test_position()
+        };
+        let mut market = create_test_market(1_000);
 
-        let winnings = match outcome {
-            Some(true) => shares_yes,
-            Some(false) => shares_no,
-            None => shares_yes + shares_no,
+        // Fully funded market refunds exactly the net contribution
+        assert_eq!(market.refund_owed(&position, 1_000).unwrap(), 200); // Not 1_100
+
+        // The next claimer's share is computed over the remaining positions
+        market.release_refund(&position).unwrap();
+        assert_eq!(market.total_net_contribution, 800);
+        market.total_net_contribution = 100;
+        assert!(market.release_refund(&position).is_err());
+    }
+
+    #[test]
+    fn test_winnings_invalid_outcome_legacy_market() {
+        // Opened before net contributions were tracked: nothing recorded
+        let position = UserPosition {
+            shares_yes: 500,
+            total_invested: 1_100,
+            trades_count: 2,
+            ..UserPosition::test_position()
         };
+        let mut market = MarketAccount {
+            refund_accounting: RefundAccounting::Legacy,
+            total_volume: 5_000,
+            ..create_test_market(0)
+        };
+
+        // Refunded what it paid in, as far as the market can pay
+        assert_eq!(market.outstanding_refunds(), 5_000);
+        assert_eq!(market.refund_owed(&position, 10_000).unwrap(), 1_100);
+        assert_eq!(market.refund_owed(&position, 600).unwrap(), 600);
 
-        assert_eq!(winnings, 150); // Full refund
+        // First refund seeds the reserve with total_volume, then draws it down
+        market.release_refund(&position).unwrap();
+        assert_eq!(market.refund_accounting, RefundAccounting::LegacySeeded);
+        assert_eq!(market.outstanding_refunds(), 3_900);
+    }
+
+    fn create_test_market(total_net_contribution: u64) -> MarketAccount {
+        MarketAccount {
+            market_id: [1; 32],
+            state: MarketState::Finalized,
+            b_parameter: 1_000_000_000,
+            initial_liquidity: 100_000_000,
+            current_liquidity: 100_000_000,
+            resolver: Pubkey::new_unique(),
+            proposed_outcome: Some(MarketOutcome::Invalid),
+            final_outcome: Some(MarketOutcome::Invalid),
+            total_net_contribution,
+            ..MarketAccount::test_market()
+        }
     }
 
     #[test]
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState};
+use crate::utils::{max_transferable_amount, transfer_from_pda_with_data};
+
+/// Close a finalized market and sweep its remainder to the protocol treasury
+///
+/// Allowed once every LP share has been redeemed and either every position
+/// has been settled, the creator has withdrawn liquidity, and the resolver
+/// has been paid, or the `GlobalConfig` claim period has passed since
+/// finalization. The withdraw_liquidity safety margin, rounding dust, and
+/// anything left unclaimed go to `protocol_fee_wallet`. The market is
+/// retired as a CLOSED tombstone that keeps its rent, so its market_id
+/// cannot be reused by a market that unclaimed positions would attach to.
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+/// * `InvalidMarketState` - If market is not FINALIZED
+/// * `MarketNotClosable` - If LP shares are outstanding, or claims are
+///   outstanding before the deadline
+#[derive(Accounts)]
+pub struct CloseMarket<'info> {
+    /// Protocol admin
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump,
+        constraint = market.state == MarketState::Finalized @ ErrorCode::InvalidMarketState
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    /// Protocol fee wallet (validated against global_config)
+    /// CHECK: Validated with constraint
+    #[account(
+        mut,
+        constraint = protocol_fee_wallet.key() == global_config.protocol_fee_wallet @ ErrorCode::Unauthorized
+    )]
+    pub protocol_fee_wallet: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let clock = Clock::get()?;
+
+    require!(
+        market.can_close(ctx.accounts.global_config.claim_window(), clock.unix_timestamp),
+        ErrorCode::MarketNotClosable
+    );
+
+    let unsettled_positions = market.open_positions;
+    let swept_amount = max_transferable_amount(&market.to_account_info())?;
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.protocol_fee_wallet.to_account_info(),
+        swept_amount,
+    )?;
+    market.retire()?;
+
+    emit!(MarketClosed {
+        market_id: market.market_id,
+        swept_amount,
+        unsettled_positions,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketClosed {
+    pub market_id: [u8; 32],
+    pub swept_amount: u64,
+    pub unsettled_positions: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{MarketAccount, MarketState, UserPosition};
+
+/// Close a user position and return its rent to the user
+///
+/// Allowed once the position is settled:
+/// - winnings or refund already claimed
+/// - no shares and no net contribution (nothing was ever owed)
+/// - market finalized and the position holds no winning shares
+/// - market already CLOSED (or its account closed)
+///
+/// # Errors
+///
+/// * `PositionNotClosable` - If the position still has a payout or refund to claim
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    /// CHECK: Market this position belongs to. May already be closed, so it is
+    /// loaded manually via `MarketAccount::try_load_open`.
+    #[account(mut, address = position.market)]
+    pub market: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", market.key().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        has_one = user,
+        close = user
+    )]
+    pub position: Account<'info, UserPosition>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
+    let position = &ctx.accounts.position;
+
+    // A closed market has nothing left to pay out
+    if let Some(mut market) = MarketAccount::try_load_open(&ctx.accounts.market)? {
+        if market.state != MarketState::Closed {
+            require!(
+                position.can_close(market.final_outcome),
+                ErrorCode::PositionNotClosable
+            );
+
+            // Claimed positions were already settled at claim time
+            if !position.has_claimed {
+                market.settle_position();
+                market.store(&ctx.accounts.market)?;
+            }
+        }
+    }
+
+    // Anchor `close = user` returns the rent and zeroes the account
+    emit!(PositionClosed {
+        market: position.market,
+        user: position.user,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PositionClosed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
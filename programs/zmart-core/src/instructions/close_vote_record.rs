@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{MarketAccount, VoteRecord};
+
+/// Close a vote record and return its rent to the voter
+///
+/// Allowed once the relevant voting phase is over:
+/// - Proposal votes: market has left PROPOSED state
+/// - Dispute votes: market is FINALIZED or CANCELLED
+/// - Either: market account already closed
+///
+/// The vote counts are already recorded on the market by aggregation, so the
+/// record is no longer needed; the voting phase cannot be re-entered, so the
+/// freed PDA cannot be used to vote twice.
+///
+/// # Errors
+///
+/// * `VotingNotEnded` - If the voting phase for this record is still ongoing
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    /// CHECK: Market this vote belongs to. May already be closed, so it is
+    /// loaded manually via `MarketAccount::try_load_open`.
+    #[account(address = vote_record.market)]
+    pub market: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vote",
+            market.key().as_ref(),
+            user.key().as_ref(),
+            &[vote_record.vote_type as u8]
+        ],
+        bump = vote_record.bump,
+        has_one = user,
+        close = user
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    let vote_record = &ctx.accounts.vote_record;
+
+    if let Some(market) = MarketAccount::try_load_open(&ctx.accounts.market)? {
+        require!(
+            vote_record.can_close(market.state),
+            ErrorCode::VotingNotEnded
+        );
+    }
+
+    // Anchor `close = user` returns the rent and zeroes the account
+    emit!(VoteRecordClosed {
+        market: vote_record.market,
+        user: vote_record.user,
+        vote_type: vote_record.vote_type as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteRecordClosed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub vote_type: u8,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MarketState, VoteType};
+
+    fn create_test_vote(vote_type: VoteType) -> VoteRecord {
+        VoteRecord {
+            market: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            vote_type,
+            vote: true,
+            voted_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_proposal_vote_closable_after_proposal_phase() {
+        let vote = create_test_vote(VoteType::Proposal);
+
+        assert!(!vote.can_close(MarketState::Proposed));
+        assert!(vote.can_close(MarketState::Approved));
+        assert!(vote.can_close(MarketState::Rejected));
+        assert!(vote.can_close(MarketState::Cancelled));
+    }
+
+    #[test]
+    fn test_dispute_vote_closable_after_finalization() {
+        let vote = create_test_vote(VoteType::Dispute);
+
+        assert!(!vote.can_close(MarketState::Disputed));
+        // Successful dispute returns to RESOLVING before finalization
+        assert!(!vote.can_close(MarketState::Resolving));
+        assert!(vote.can_close(MarketState::Finalized));
+        assert!(vote.can_close(MarketState::Cancelled));
+        assert!(vote.can_close(MarketState::Closed));
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use anchor_lang::system_program;
+use crate::state::{CurveType, FeeSchedule, GlobalConfig, MarketAccount, MarketState, RefundAccounting, PAUSE_CREATE};
 use crate::error::ErrorCode;
+use crate::math::bounded_loss::calculate_max_loss;
+use crate::math::{lmsr, ls_lmsr, MAX_B, MIN_B};
 
 /// Create a new prediction market in PROPOSED state
 ///
@@ -8,18 +11,33 @@ use crate::error::ErrorCode;
 /// proposal voting approval. The market will need 70% approval to transition
 /// to APPROVED state, then admin/creator activation to become ACTIVE.
 ///
+/// The creator deposits `initial_liquidity` into the market as the LMSR
+/// subsidy. It must cover the worst-case market maker loss of b * ln(2), and
+/// whatever remains is returned via withdraw_liquidity or reclaim_market.
+/// The subsidy mints the creator's LP shares 1:1; other wallets can add
+/// liquidity before activation with add_liquidity.
+///
+/// `create_market_from_subsidy` uses the same accounts but takes only the
+/// subsidy and derives the deepest b it can back (b = subsidy / ln(2)).
+///
+/// `create_ls_lmsr_market` creates a liquidity-sensitive LMSR market instead,
+/// whose b grows with the shares outstanding (b = α * Σq).
+///
+/// `create_parimutuel_market` creates a pool market with no market maker:
+/// stakes buy shares 1:1 and need no subsidy.
+///
 /// # Arguments
 ///
 /// * `market_id` - Unique identifier for this market (32 bytes, used in PDA seeds)
-/// * `b_parameter` - LMSR liquidity sensitivity parameter (must be > 0)
-/// * `initial_liquidity` - Starting liquidity in lamports (must be > 0)
+/// * `b_parameter` - LMSR liquidity sensitivity parameter (MIN_B..=MAX_B)
+/// * `initial_liquidity` - Starting liquidity in lamports (must cover b * ln(2))
 /// * `ipfs_question_hash` - IPFS CID for market question/description (46 bytes)
 ///
 /// # Errors
 ///
 /// * `ProtocolPaused` - If protocol is paused
-/// * `InvalidBParameter` - If b_parameter < MIN_B
-/// * `InvalidLiquidity` - If initial_liquidity == 0
+/// * `InvalidBParameter` - If b_parameter is outside MIN_B..=MAX_B
+/// * `InvalidLiquidity` - If initial_liquidity is below the LMSR worst-case loss b * ln(2)
 #[derive(Accounts)]
 #[instruction(market_id: [u8; 32])]
 pub struct CreateMarket<'info> {
@@ -59,43 +77,136 @@ pub fn handler(
     initial_liquidity: u64,
     ipfs_question_hash: [u8; 46],
 ) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
-    let market = &mut ctx.accounts.market;
-
-    // Validate protocol is not paused
+    // Validate LMSR parameter (trading rejects b < MIN_B, so such a market
+    // could never be traded)
     require!(
-        !global_config.is_paused,
-        ErrorCode::ProtocolPaused
+        (MIN_B..=MAX_B).contains(&b_parameter),
+        ErrorCode::InvalidBParameter
     );
 
-    // Validate LMSR parameter
+    // Validate initial liquidity covers the LMSR worst-case loss (b * ln(2))
     require!(
-        b_parameter > 0,
-        ErrorCode::InvalidBParameter
+        initial_liquidity >= calculate_max_loss(b_parameter)?,
+        ErrorCode::InvalidLiquidity
     );
 
-    // Validate initial liquidity
+    initialize_market(
+        ctx,
+        market_id,
+        CurveType::Lmsr,
+        0,
+        b_parameter,
+        initial_liquidity,
+        ipfs_question_hash,
+    )
+}
+
+/// Handler for create_ls_lmsr_market instruction
+///
+/// The whole subsidy seeds the initial share state: its cost C(q0, q0) is
+/// the LS-LMSR worst-case loss, so the starting depth is the deepest one the
+/// subsidy covers.
+///
+/// # Errors
+///
+/// * `InvalidAlphaParameter` - If alpha is outside MIN_ALPHA..=MAX_ALPHA
+/// * `InvalidLiquidity` - If the subsidy buys less than one share per outcome
+pub fn handler_ls_lmsr(
+    ctx: Context<CreateMarket>,
+    market_id: [u8; 32],
+    alpha: u64,
+    initial_liquidity: u64,
+    ipfs_question_hash: [u8; 46],
+) -> Result<()> {
     require!(
-        initial_liquidity > 0,
-        ErrorCode::InvalidLiquidity
+        (ls_lmsr::MIN_ALPHA..=ls_lmsr::MAX_ALPHA).contains(&alpha),
+        ErrorCode::InvalidAlphaParameter
     );
 
+    let b_parameter = ls_lmsr::b_from_subsidy(initial_liquidity, alpha)?;
+
+    initialize_market(
+        ctx,
+        market_id,
+        CurveType::LsLmsr,
+        alpha,
+        b_parameter,
+        initial_liquidity,
+        ipfs_question_hash,
+    )
+}
+
+/// Handler for create_parimutuel_market instruction
+///
+/// Parimutuel markets start empty: no subsidy, no initial shares, and no
+/// pricing parameters. The pool belongs to bettors, so withdraw_liquidity
+/// is rejected for these markets. If nobody stakes on the winning outcome,
+/// the pool is left for sweep_unclaimed after the claim period.
+pub fn handler_parimutuel(
+    ctx: Context<CreateMarket>,
+    market_id: [u8; 32],
+    ipfs_question_hash: [u8; 46],
+) -> Result<()> {
+    initialize_market(
+        ctx,
+        market_id,
+        CurveType::Parimutuel,
+        0,
+        0,
+        0,
+        ipfs_question_hash,
+    )
+}
+
+/// Initialize the market account and escrow the subsidy
+///
+/// Callers validate the curve parameters and that `initial_liquidity`
+/// covers the curve's worst-case loss.
+fn initialize_market(
+    ctx: Context<CreateMarket>,
+    market_id: [u8; 32],
+    curve_type: CurveType,
+    alpha: u64,
+    b_parameter: u64,
+    initial_liquidity: u64,
+    ipfs_question_hash: [u8; 46],
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let market = &mut ctx.accounts.market;
+
+    // Validate market creation is not paused
+    global_config.require_not_paused(PAUSE_CREATE)?;
+
     // Initialize market in PROPOSED state
     market.market_id = market_id;
     market.creator = ctx.accounts.creator.key();
     market.state = MarketState::Proposed;
 
-    // Set LMSR parameters
+    // Set pricing parameters
+    market.curve_type = curve_type;
+    market.alpha = alpha;
     market.b_parameter = b_parameter;
     market.initial_liquidity = initial_liquidity;
     market.current_liquidity = initial_liquidity;
 
+    // The creator's subsidy mints LP shares 1:1; third parties can join
+    // before activation via add_liquidity
+    market.total_lp_shares = initial_liquidity;
+    market.creator_lp_shares = initial_liquidity;
+
     // CRITICAL FIX: Initialize with small equal shares to create 50/50 starting price
     // Use a small fraction of b (liquidity depth) rather than initial_liquidity
     // Formula: initial_shares = b / 1000 gives reasonable starting point
     // This keeps initial cost = b * ln(2 * e^0.001) ≈ b * 0.694 manageable
     // Setting shares_yes = shares_no creates P(YES) = P(NO) = 0.5
-    let initial_shares = b_parameter / 1000;
+    //
+    // LS-LMSR markets instead start with the q0 whose depth 2α * q0 is b;
+    // their subsidy pays for exactly this inventory.
+    let initial_shares = match curve_type {
+        CurveType::Lmsr => b_parameter / 1000,
+        CurveType::LsLmsr => ls_lmsr::initial_shares(b_parameter, alpha)?,
+        CurveType::Parimutuel => 0,
+    };
     market.shares_yes = initial_shares;
     market.shares_no = initial_shares;
     market.total_volume = 0;
@@ -133,14 +244,36 @@ pub fn handler(
     // Initialize state flags
     market.is_cancelled = false;
     market.is_locked = false;  // SECURITY FIX (Finding #8): Initialize reentrancy guard
+    market.resolver_fees_claimed = false;
+    market.total_net_contribution = 0;
+    market.refund_accounting = RefundAccounting::NetContribution;
+    market.dispute_outcome = 0;
+    market.open_positions = 0;
+    market.tracks_open_positions = true;
+    market.claim_deadline = 0;
+    market.has_fee_snapshot = false;
+    market.fee_snapshot = FeeSchedule::default();
+    market.is_frozen = false;
     market.bump = ctx.bumps.market;
 
-    // Initialize reserved space (119 bytes, 1 byte used by is_locked)
-    market.reserved = [0; 119];
+    // Initialize reserved space (62 bytes)
+    market.reserved = [0; 62];
 
     // SECURITY FIX (Finding #12): Validate reserved fields are zeroed
     market.validate_reserved()?;
 
+    // Escrow the subsidy so current_liquidity is backed by lamports
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: market.to_account_info(),
+            },
+        ),
+        initial_liquidity,
+    )?;
+
     msg!(
         "Market created: {:?} by creator: {} with b={}, liquidity={}",
         market_id,
@@ -154,6 +287,7 @@ pub fn handler(
         market_id,
         creator: ctx.accounts.creator.key(),
         b_parameter,
+        curve_type,
         state: market.state as u8,
         timestamp: market.created_at,
     });
@@ -161,11 +295,38 @@ pub fn handler(
     Ok(())
 }
 
+/// Handler for create_market_from_subsidy instruction
+///
+/// # Errors
+///
+/// * `InvalidBParameter` - If the subsidy would back a b above MAX_B
+/// * `InvalidLiquidity` - If the subsidy cannot cover MIN_B's worst-case loss
+pub fn handler_from_subsidy(
+    ctx: Context<CreateMarket>,
+    market_id: [u8; 32],
+    subsidy: u64,
+    ipfs_question_hash: [u8; 46],
+) -> Result<()> {
+    let b_parameter = b_parameter_from_subsidy(subsidy)?;
+    handler(ctx, market_id, b_parameter, subsidy, ipfs_question_hash)
+}
+
+/// Deepest b parameter whose worst-case loss `subsidy` covers
+pub(crate) fn b_parameter_from_subsidy(subsidy: u64) -> Result<u64> {
+    // Checked up front: lmsr::calculate_b_parameter truncates oversized results
+    require!(
+        subsidy <= calculate_max_loss(MAX_B)?,
+        ErrorCode::InvalidBParameter
+    );
+    Ok(lmsr::calculate_b_parameter(subsidy))
+}
+
 #[event]
 pub struct MarketCreated {
     pub market_id: [u8; 32],
     pub creator: Pubkey,
     pub b_parameter: u64,
+    pub curve_type: CurveType,
     pub state: u8,
     pub timestamp: i64,
 }
@@ -182,43 +343,11 @@ mod tests {
         MarketAccount {
             market_id: [1; 32],
             creator,
-            state: MarketState::Proposed,
             b_parameter: b,
             initial_liquidity: liquidity,
             current_liquidity: liquidity,
-            shares_yes: 0,
-            shares_no: 0,
-            total_volume: 0,
             created_at: 1730000000,
-            approved_at: 0,
-            activated_at: 0,
-            resolution_proposed_at: 0,
-            resolved_at: 0,
-            finalized_at: 0,
-            resolver: Pubkey::default(),
-            proposed_outcome: None,
-            final_outcome: None,
-            ipfs_evidence_hash: [0; 46],
-            dispute_initiated_at: 0,
-            dispute_initiator: Pubkey::default(),
-            accumulated_protocol_fees: 0,
-            accumulated_resolver_fees: 0,
-            accumulated_lp_fees: 0,
-            proposal_likes: 0,
-            proposal_dislikes: 0,
-            proposal_total_votes: 0,
-            resolution_agree: 0,
-            resolution_disagree: 0,
-            resolution_total_votes: 0,
-            dispute_agree: 0,
-            dispute_disagree: 0,
-            dispute_total_votes: 0,
-            was_disputed: false,
-            is_cancelled: false,
-            cancelled_at: None,
-            bump: 255,
-            is_locked: false,
-            reserved: [0; 119],
+            ..MarketAccount::test_market()
         }
     }
 
@@ -294,19 +423,44 @@ mod tests {
 
     #[test]
     fn test_parameter_validation_requirements() {
-        // b_parameter must be > 0
-        let invalid_b = 0u64;
-        assert_eq!(invalid_b, 0); // Would trigger InvalidBParameter
-
-        let valid_b = 1_000_000_000u64;
-        assert!(valid_b > 0);
+        // b_parameter must be within MIN_B..=MAX_B
+        let valid_range = MIN_B..=MAX_B;
+        assert!(!valid_range.contains(&0)); // Would trigger InvalidBParameter
+        assert!(!valid_range.contains(&(MIN_B - 1)));
+        assert!(valid_range.contains(&MIN_B));
+        assert!(valid_range.contains(&MAX_B));
+        assert!(!valid_range.contains(&(MAX_B + 1)));
+
+        // initial_liquidity must cover b * ln(2)
+        let max_loss = calculate_max_loss(MIN_B).unwrap();
+        assert_eq!(max_loss, 69_314_718_000); // ≈ 69.3 SOL
+    }
 
-        // initial_liquidity must be > 0
-        let invalid_liquidity = 0u64;
-        assert_eq!(invalid_liquidity, 0); // Would trigger InvalidLiquidity
+    #[test]
+    fn test_b_parameter_from_subsidy() {
+        // 693.147180 SOL subsidy backs b = 1000 SOL
+        let b = b_parameter_from_subsidy(693_147_180_000).unwrap();
+        assert_eq!(b, 1_000 * crate::math::PRECISION);
+        assert!(calculate_max_loss(b).unwrap() <= 693_147_180_000);
+
+        // Derived b always passes create_market's checks
+        for subsidy in [calculate_max_loss(MIN_B).unwrap(), 123_456_789_012, calculate_max_loss(MAX_B).unwrap()] {
+            let b = b_parameter_from_subsidy(subsidy).unwrap();
+            assert!((MIN_B..=MAX_B).contains(&b));
+            assert!(subsidy >= calculate_max_loss(b).unwrap());
+        }
+    }
 
-        let valid_liquidity = 10_000_000_000u64;
-        assert!(valid_liquidity > 0);
+    #[test]
+    fn test_b_parameter_from_subsidy_bounds() {
+        // Too small: clamped to MIN_B, which the subsidy can't cover
+        let b = b_parameter_from_subsidy(1_000_000_000).unwrap();
+        assert_eq!(b, MIN_B);
+        assert!(1_000_000_000 < calculate_max_loss(b).unwrap());
+
+        // Too large for MAX_B
+        let over = calculate_max_loss(MAX_B).unwrap() + 1_000_000_000;
+        assert!(b_parameter_from_subsidy(over).is_err());
     }
 
     #[test]
@@ -317,7 +471,7 @@ mod tests {
         assert_eq!(market.is_cancelled, false);
 
         // Reserved space should be zeroed
-        assert_eq!(market.reserved, [0; 119]);
+        assert_eq!(market.reserved, [0; 62]);
     }
 
     #[test]
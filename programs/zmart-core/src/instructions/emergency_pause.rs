@@ -1,74 +1,93 @@
 use anchor_lang::prelude::*;
-use crate::state::GlobalConfig;
+use crate::state::{GlobalConfig, PauseAuthority};
 use crate::error::ErrorCode;
 
-/// Toggle protocol pause state (pause/unpause trading)
+/// Set which protocol operations are paused
 ///
-/// Allows protocol admin to pause all trading operations in case of critical
-/// bugs, exploits, or market instability. This is essential for operational
-/// safety and mainnet readiness.
+/// Allows protocol admin or guardian to pause operations in case of critical
+/// bugs, exploits, or market instability. Create, buy, sell, claim, vote and
+/// resolve can be paused independently via the PAUSE_* flags.
 ///
-/// Calling this instruction when protocol is running pauses all trading.
-/// Calling it again when protocol is paused unpauses (resumes) trading.
-/// Voting and resolution operations are NOT affected by pause.
+/// The call sets the full pause state rather than toggling it, so retrying
+/// a transaction can never undo a pause. Pass `PAUSE_ALL` to stop everything
+/// and `0` to resume. The guardian can only add flags; clearing any flag
+/// requires the admin.
+///
+/// # Arguments
+///
+/// * `paused_operations` - Bitmask of PAUSE_* flags to pause
 ///
 /// # Errors
 ///
-/// * `Unauthorized` - If signer is not admin
+/// * `Unauthorized` - If signer is not admin or guardian, or the guardian
+///   tries to clear a pause flag
+/// * `InvalidPauseFlags` - If unknown flag bits are set
 #[derive(Accounts)]
 pub struct EmergencyPause<'info> {
-    /// Protocol admin with pause authority
+    /// Protocol admin or guardian
     pub admin: Signer<'info>,
 
     /// Global configuration account to update
     #[account(
         mut,
         seeds = [b"global-config"],
-        bump = global_config.bump,
-        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+        bump = global_config.bump
     )]
     pub global_config: Account<'info, GlobalConfig>,
 }
 
 /// Handler for emergency_pause instruction
-pub fn handler(ctx: Context<EmergencyPause>) -> Result<()> {
+pub fn handler(ctx: Context<EmergencyPause>, paused_operations: u8) -> Result<()> {
     let config = &mut ctx.accounts.global_config;
     let clock = Clock::get()?;
 
-    // Toggle pause state
-    let new_paused_state = !config.is_paused;
-    config.is_paused = new_paused_state;
+    let role = config.pause_authority(&ctx.accounts.admin.key())?;
+    require!(
+        can_set_pause(role, config.paused_flags(), paused_operations),
+        ErrorCode::Unauthorized
+    );
+
+    config.set_paused_operations(paused_operations)?;
 
     // Emit event with new pause status
     emit!(ProtocolPauseStatusChanged {
-        is_paused: new_paused_state,
+        paused_operations,
         paused_by: ctx.accounts.admin.key(),
+        role,
         timestamp: clock.unix_timestamp,
     });
 
-    if new_paused_state {
-        msg!("Protocol PAUSED by admin");
-    } else {
-        msg!("Protocol UNPAUSED by admin");
-    }
+    msg!("Protocol paused operations set to {:#08b} by {:?}", paused_operations, role);
 
     Ok(())
 }
 
+/// Whether `role` may change the pause state from `current` to `requested`
+///
+/// The guardian may only pause, so it must keep every flag already set.
+pub(crate) fn can_set_pause(role: PauseAuthority, current: u8, requested: u8) -> bool {
+    match role {
+        PauseAuthority::Admin => true,
+        PauseAuthority::Guardian => requested & current == current,
+    }
+}
+
 /// Event emitted when protocol pause status changes
 #[event]
 pub struct ProtocolPauseStatusChanged {
-    pub is_paused: bool,
+    pub paused_operations: u8,
     pub paused_by: Pubkey,
+    pub role: PauseAuthority,
     pub timestamp: i64,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::{LEGACY_PAUSED, PAUSE_ALL, PAUSE_BUY, PAUSE_CREATE, PAUSE_SELL, PAUSE_VOTE};
     use anchor_lang::solana_program::pubkey::Pubkey;
 
-    fn create_test_config(is_paused: bool) -> GlobalConfig {
+    fn create_test_config(paused_operations: u8) -> GlobalConfig {
         GlobalConfig {
             admin: Pubkey::new_unique(),
             backend_authority: Pubkey::new_unique(),
@@ -81,80 +100,103 @@ mod tests {
             min_resolution_delay: 86400, // 24 hours
             dispute_period: 259200, // 3 days
             min_resolver_reputation: 8000, // 80%
-            is_paused,
-            reserved: [0; 64],
+            paused_operations,
+            claim_period: 31_536_000,
+            pending_admin: Pubkey::default(),
+            guardian: Pubkey::default(),
             bump: 255,
         }
     }
 
     #[test]
     fn test_pause_when_running() {
-        // Protocol starts running (is_paused = false)
-        let mut config = create_test_config(false);
+        let mut config = create_test_config(0);
 
-        // Toggle pause (should become paused)
-        let new_state = !config.is_paused;
-        config.is_paused = new_state;
+        config.set_paused_operations(PAUSE_ALL).unwrap();
 
-        assert!(config.is_paused);
+        assert_eq!(config.paused_operations, PAUSE_ALL);
+        assert!(config.require_not_paused(PAUSE_BUY).is_err());
     }
 
     #[test]
     fn test_unpause_when_paused() {
-        // Protocol starts paused (is_paused = true)
-        let mut config = create_test_config(true);
+        let mut config = create_test_config(PAUSE_ALL);
+
+        config.set_paused_operations(0).unwrap();
+
+        assert_eq!(config.paused_operations, 0);
+        assert!(config.require_not_paused(PAUSE_ALL).is_ok());
+    }
+
+    #[test]
+    fn test_retried_pause_stays_paused() {
+        // A retried transaction repeats the same call instead of toggling
+        let mut config = create_test_config(0);
 
-        // Toggle pause (should become unpaused)
-        let new_state = !config.is_paused;
-        config.is_paused = new_state;
+        config.set_paused_operations(PAUSE_BUY | PAUSE_SELL).unwrap();
+        config.set_paused_operations(PAUSE_BUY | PAUSE_SELL).unwrap();
 
-        assert!(!config.is_paused);
+        assert_eq!(config.paused_operations, PAUSE_BUY | PAUSE_SELL);
     }
 
     #[test]
-    fn test_multiple_toggles() {
-        // Start running
-        let mut config = create_test_config(false);
-        assert!(!config.is_paused);
-
-        // Pause
-        config.is_paused = !config.is_paused;
-        assert!(config.is_paused);
-
-        // Unpause
-        config.is_paused = !config.is_paused;
-        assert!(!config.is_paused);
-
-        // Pause again
-        config.is_paused = !config.is_paused;
-        assert!(config.is_paused);
+    fn test_partial_pause() {
+        // Pausing trading leaves market creation and voting available
+        let mut config = create_test_config(0);
+        config.set_paused_operations(PAUSE_BUY | PAUSE_SELL).unwrap();
+
+        assert!(config.require_not_paused(PAUSE_SELL).is_err());
+        assert!(config.require_not_paused(PAUSE_CREATE).is_ok());
+        assert!(config.require_not_paused(PAUSE_VOTE).is_ok());
     }
 
     #[test]
-    fn test_pause_state_toggle() {
-        let mut states = vec![false, true, false, true];
-        let mut current = false;
+    fn test_unknown_flags_rejected() {
+        let mut config = create_test_config(0);
 
-        for expected in states {
-            assert_eq!(current, expected);
-            current = !current; // Toggle
-        }
+        assert!(config.set_paused_operations(LEGACY_PAUSED).is_err());
+        assert!(config.set_paused_operations(PAUSE_ALL | LEGACY_PAUSED).is_err());
+        assert_eq!(config.paused_operations, 0);
+    }
+
+    #[test]
+    fn test_guardian_can_only_pause() {
+        let mut config = create_test_config(PAUSE_BUY);
+        let guardian = Pubkey::new_unique();
+        config.guardian = guardian;
+
+        let role = config.pause_authority(&guardian).unwrap();
+        assert_eq!(role, PauseAuthority::Guardian);
+
+        // Adding flags is allowed, clearing any is not
+        assert!(can_set_pause(role, PAUSE_BUY, PAUSE_BUY | PAUSE_SELL));
+        assert!(can_set_pause(role, PAUSE_BUY, PAUSE_BUY));
+        assert!(!can_set_pause(role, PAUSE_BUY, 0));
+        assert!(!can_set_pause(role, PAUSE_BUY, PAUSE_SELL));
+
+        // Admin may unpause
+        let admin = config.pause_authority(&config.admin).unwrap();
+        assert!(can_set_pause(admin, PAUSE_BUY, 0));
+    }
+
+    #[test]
+    fn test_pause_authority_requires_role() {
+        let config = create_test_config(0);
 
-        // After 4 toggles, should be back to original state (false)
-        assert!(!current);
+        // Unset guardian (default pubkey) grants nothing
+        assert!(config.pause_authority(&Pubkey::default()).is_err());
+        assert!(config.pause_authority(&Pubkey::new_unique()).is_err());
     }
 
     #[test]
     fn test_pause_flag_independence() {
         // Pause state is independent of other config fields
-        let mut config = create_test_config(false);
+        let mut config = create_test_config(0);
 
-        // Toggle pause
-        config.is_paused = !config.is_paused;
+        config.set_paused_operations(PAUSE_ALL).unwrap();
 
         // Verify other fields unchanged
         assert_eq!(config.protocol_fee_bps, 300);
         assert_eq!(config.proposal_approval_threshold, 7000);
-        assert!(config.is_paused);
     }
 }
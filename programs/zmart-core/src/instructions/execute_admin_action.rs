@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::InstructionData;
+use crate::program::ZmartCore;
+use crate::state::{AdminAction, AdminMultisig, GlobalConfig, PendingAdminAction};
+use crate::error::ErrorCode;
+
+/// Execute an admin action that reached the multisig threshold
+///
+/// Admin instructions are invoked through CPI into this program, with the
+/// multisig PDA signing as `admin`, so they keep their own validation.
+/// Market-targeted actions take the market as the first remaining account
+/// (then the protocol fee wallet for CloseMarket and SweepUnclaimed);
+/// config actions take the PendingConfig PDA (then the system program for
+/// UpdateGlobalConfig, whose rent the executor pays). UpdateProtocolFeeWallet
+/// takes the new wallet.
+/// The PendingAdminAction is closed and its rent returned to the proposer.
+///
+/// # Errors
+///
+/// * `NotMultisigSigner` - If executor is not in the signer set
+/// * `StaleAdminAction` - If the signer set changed since the action was proposed
+/// * `InsufficientApprovals` - If approvals are below the threshold
+/// * `InvalidAdminActionAccounts` - If the remaining accounts do not match the action
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    /// Multisig signer executing the action
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin-multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        has_one = proposer,
+        constraint = pending_action.signer_set_seq == multisig.signer_set_seq @ ErrorCode::StaleAdminAction,
+        close = proposer
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    /// CHECK: Receives the pending action rent, validated by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: Global config PDA. Left unchecked so this instruction does not
+    /// write back a stale copy over changes made by the CPI.
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub zmart_program: Program<'info, ZmartCore>,
+}
+
+/// Handler for execute_admin_action instruction
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteAdminAction<'info>>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let pending_action = &ctx.accounts.pending_action;
+
+    multisig.signer_index(&ctx.accounts.executor.key())?;
+    require!(
+        pending_action.approval_count() >= multisig.threshold as u32,
+        ErrorCode::InsufficientApprovals
+    );
+
+    let action = pending_action.action.clone();
+    let admin = multisig.key();
+    let global_config = ctx.accounts.global_config.key();
+    let executor = ctx.accounts.executor.key();
+    let (pending_config, _) = Pubkey::find_program_address(&[b"pending-config"], &crate::ID);
+
+    // Admin instruction to run, with the multisig PDA as the `admin` signer
+    let instruction = match action.clone() {
+        AdminAction::ApproveProposal { market } => {
+            expect_accounts(ctx.remaining_accounts, &[market])?;
+            admin_instruction(
+                crate::accounts::ApproveProposal { admin, market, global_config },
+                crate::instruction::ApproveProposal {},
+            )
+        }
+        AdminAction::CancelMarket { market } => {
+            expect_accounts(ctx.remaining_accounts, &[market])?;
+            admin_instruction(
+                crate::accounts::CancelMarket { admin, market, global_config },
+                crate::instruction::CancelMarket {},
+            )
+        }
+        AdminAction::EmergencyPause { paused_operations } => admin_instruction(
+            crate::accounts::EmergencyPause { admin, global_config },
+            crate::instruction::EmergencyPause { paused_operations },
+        ),
+        AdminAction::UpdateGlobalConfig {
+            protocol_fee_bps,
+            resolver_reward_bps,
+            liquidity_provider_fee_bps,
+            proposal_approval_threshold,
+            dispute_success_threshold,
+            min_resolution_delay,
+            dispute_period,
+            claim_period,
+        } => {
+            let system_program = anchor_lang::system_program::ID;
+            expect_accounts(ctx.remaining_accounts, &[pending_config, system_program])?;
+            admin_instruction(
+                crate::accounts::UpdateGlobalConfig {
+                    admin,
+                    global_config,
+                    pending_config,
+                    payer: executor,
+                    system_program,
+                },
+                crate::instruction::UpdateGlobalConfig {
+                    protocol_fee_bps,
+                    resolver_reward_bps,
+                    liquidity_provider_fee_bps,
+                    proposal_approval_threshold,
+                    dispute_success_threshold,
+                    min_resolution_delay,
+                    dispute_period,
+                    claim_period,
+                },
+            )
+        }
+        AdminAction::FreezeMarket { market, frozen } => {
+            expect_accounts(ctx.remaining_accounts, &[market])?;
+            admin_instruction(
+                crate::accounts::FreezeMarket { admin, market, global_config },
+                crate::instruction::FreezeMarket { frozen },
+            )
+        }
+        AdminAction::CancelConfigUpdate => {
+            expect_accounts(ctx.remaining_accounts, &[pending_config])?;
+            admin_instruction(
+                crate::accounts::CancelConfigUpdate { admin, global_config, pending_config },
+                crate::instruction::CancelConfigUpdate {},
+            )
+        }
+        AdminAction::ProposeAdmin { new_admin } => admin_instruction(
+            crate::accounts::ProposeAdmin { admin, global_config },
+            crate::instruction::ProposeAdmin { new_admin },
+        ),
+        AdminAction::UpdateGuardian { new_guardian } => admin_instruction(
+            crate::accounts::UpdateGuardian { admin, global_config },
+            crate::instruction::UpdateGuardian { new_guardian },
+        ),
+        AdminAction::UpdateBackendAuthority { new_backend_authority } => admin_instruction(
+            crate::accounts::UpdateBackendAuthority { admin, global_config },
+            crate::instruction::UpdateBackendAuthority { new_backend_authority },
+        ),
+        AdminAction::UpdateProtocolFeeWallet { protocol_fee_wallet } => {
+            expect_accounts(ctx.remaining_accounts, &[protocol_fee_wallet])?;
+            admin_instruction(
+                crate::accounts::UpdateProtocolFeeWallet { admin, global_config, protocol_fee_wallet },
+                crate::instruction::UpdateProtocolFeeWallet {},
+            )
+        }
+        AdminAction::CloseMarket { market } => {
+            let protocol_fee_wallet = current_protocol_fee_wallet(&ctx.accounts.global_config)?;
+            expect_accounts(ctx.remaining_accounts, &[market, protocol_fee_wallet])?;
+            admin_instruction(
+                crate::accounts::CloseMarket { admin, global_config, market, protocol_fee_wallet },
+                crate::instruction::CloseMarket {},
+            )
+        }
+        AdminAction::SweepUnclaimed { market } => {
+            let protocol_fee_wallet = current_protocol_fee_wallet(&ctx.accounts.global_config)?;
+            expect_accounts(ctx.remaining_accounts, &[market, protocol_fee_wallet])?;
+            admin_instruction(
+                crate::accounts::SweepUnclaimed { admin, global_config, market, protocol_fee_wallet },
+                crate::instruction::SweepUnclaimed {},
+            )
+        }
+        AdminAction::UpdateSigners { signers, threshold } => {
+            // Invalidates approvals on every other pending action
+            ctx.accounts.multisig.set_signers(&signers, threshold)?;
+            None
+        }
+    };
+
+    if let Some(instruction) = instruction {
+        let mut account_infos = vec![
+            ctx.accounts.multisig.to_account_info(),
+            ctx.accounts.global_config.to_account_info(),
+            ctx.accounts.zmart_program.to_account_info(),
+            ctx.accounts.executor.to_account_info(),
+        ];
+        account_infos.extend(ctx.remaining_accounts.iter().cloned());
+
+        let bump = [ctx.accounts.multisig.bump];
+        invoke_signed(&instruction, &account_infos, &[&[b"admin-multisig", &bump]])?;
+    }
+
+    emit!(AdminActionExecuted {
+        pending_action: ctx.accounts.pending_action.key(),
+        executor: ctx.accounts.executor.key(),
+        action,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Check the remaining accounts are exactly the ones the action needs
+fn expect_accounts(remaining_accounts: &[AccountInfo], expected: &[Pubkey]) -> Result<()> {
+    require!(
+        remaining_accounts.len() == expected.len()
+            && remaining_accounts.iter().zip(expected).all(|(info, key)| info.key == key),
+        ErrorCode::InvalidAdminActionAccounts
+    );
+    Ok(())
+}
+
+/// Protocol fee wallet currently configured in the (unchecked) global config
+fn current_protocol_fee_wallet(global_config: &AccountInfo) -> Result<Pubkey> {
+    let data = global_config.try_borrow_data()?;
+    Ok(GlobalConfig::try_deserialize(&mut &data[..])?.protocol_fee_wallet)
+}
+
+/// Build a self-invocation of an admin instruction
+fn admin_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Option<Instruction> {
+    Some(Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    })
+}
+
+/// Event emitted when a multisig admin action executes
+#[event]
+pub struct AdminActionExecuted {
+    pub pending_action: Pubkey,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
@@ -1,13 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
-use crate::math::verify_bounded_loss;
+use crate::state::{GlobalConfig, MarketAccount, MarketOutcome, MarketState, PAUSE_RESOLVE};
 
 /// Set final outcome (RESOLVING/DISPUTED → FINALIZED)
 ///
 /// Backend authority finalizes the market after vote aggregation.
 /// - RESOLVING: Keep proposed outcome after dispute window expires
-/// - DISPUTED: Check if ≥60% agree to flip outcome
+/// - DISPUTED: Check if ≥60% agree to replace the outcome with the disputed one
 ///
 /// # Arguments
 /// * `dispute_agree` - Dispute agree votes (Some for DISPUTED, None for RESOLVING)
@@ -45,6 +44,9 @@ pub fn handler(
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
+    config.require_not_paused(PAUSE_RESOLVE)?;
+    market.require_not_frozen()?;
+
     // SECURITY FIX (Finding #10): Validate timestamp bounds
     // Prevents time travel and far-future manipulation attacks
     require!(
@@ -94,18 +96,11 @@ pub fn handler(
             .checked_mul(10000).ok_or(ErrorCode::OverflowError)?
             .checked_div(total as u64).ok_or(ErrorCode::DivisionByZero)?;
 
-        // Check if dispute succeeded (≥60% agree to flip)
-        if agree_rate_bps >= config.dispute_success_threshold as u64 {
-            // Dispute succeeded → flip outcome
-            match market.proposed_outcome {
-                Some(true) => Some(false),   // YES → NO
-                Some(false) => Some(true),   // NO → YES
-                None => None,                // INVALID stays INVALID
-            }
-        } else {
-            // Dispute failed → keep proposed outcome
-            market.proposed_outcome
-        }
+        // Check if dispute succeeded (≥60% agree)
+        // Succeeded → initiator's dispute_outcome (any of YES/NO/INVALID)
+        // Failed → keep proposed outcome
+        let dispute_succeeded = agree_rate_bps >= config.dispute_success_threshold as u64;
+        market.outcome_after_dispute(dispute_succeeded)
     } else {
         // RESOLVING case: No dispute occurred
         // Verify dispute window has expired
@@ -125,20 +120,13 @@ pub fn handler(
     // SECURITY FIX (Finding #5 - Week 3): Verify bounded loss protection
     // Ensure market creator loss never exceeds b * ln(2) ≈ 0.693 * b
     // This protects against bugs in LMSR implementation or numerical errors
-    // Already implemented, just updating comment for clarity
-    verify_bounded_loss(
-        market.initial_liquidity,
-        market.current_liquidity,
-        market.b_parameter,
-    )?;
-
-    // Set final outcome and mark as finalized
-    market.final_outcome = final_outcome;
-    market.was_disputed = was_disputed;
-    market.finalized_at = clock.unix_timestamp;
+    market.verify_bounded_loss()?;
 
-    // Transition state: RESOLVING → FINALIZED (using wrapper for validation)
-    market.transition_state(MarketState::Finalized)?;
+    let final_outcome = final_outcome.ok_or(ErrorCode::NoResolutionProposed)?;
+
+    // Set final outcome and claim deadline: RESOLVING → FINALIZED
+    market.was_disputed = was_disputed;
+    market.finalize(final_outcome, clock.unix_timestamp, config.claim_window())?;
 
     // Emit event
     emit!(MarketFinalized {
@@ -155,7 +143,7 @@ pub fn handler(
 #[event]
 pub struct MarketFinalized {
     pub market_id: [u8; 32],
-    pub final_outcome: Option<bool>,
+    pub final_outcome: MarketOutcome,
     pub was_disputed: bool,
     pub timestamp: i64,
 }
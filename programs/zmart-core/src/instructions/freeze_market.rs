@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, MarketAccount, PauseAuthority};
+use crate::error::ErrorCode;
+
+/// Freeze or unfreeze a single market
+///
+/// A frozen market rejects trading, claims, voting and resolution with
+/// `MarketPaused`, independently of the protocol-wide pause flags. Like
+/// emergency_pause this sets the state explicitly instead of toggling it.
+/// The guardian may freeze a market; only the admin may unfreeze it.
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin or guardian, or the guardian
+///   tries to unfreeze
+#[derive(Accounts)]
+pub struct FreezeMarket<'info> {
+    /// Protocol admin or guardian
+    pub admin: Signer<'info>,
+
+    /// Market account to freeze or unfreeze
+    #[account(
+        mut,
+        seeds = [b"market", market.market_id.as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    /// Global configuration (verify admin or guardian authority)
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Handler for freeze_market instruction
+pub fn handler(ctx: Context<FreezeMarket>, frozen: bool) -> Result<()> {
+    let role = ctx.accounts.global_config.pause_authority(&ctx.accounts.admin.key())?;
+    require!(
+        frozen || role == PauseAuthority::Admin,
+        ErrorCode::Unauthorized
+    );
+
+    let market = &mut ctx.accounts.market;
+    market.is_frozen = frozen;
+
+    emit!(MarketFreezeChanged {
+        market_id: market.market_id,
+        is_frozen: frozen,
+        changed_by: ctx.accounts.admin.key(),
+        role,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Market {:?} frozen: {}", market.market_id, frozen);
+
+    Ok(())
+}
+
+/// Event emitted when a market is frozen or unfrozen
+#[event]
+pub struct MarketFreezeChanged {
+    pub market_id: [u8; 32],
+    pub is_frozen: bool,
+    pub changed_by: Pubkey,
+    pub role: PauseAuthority,
+    pub timestamp: i64,
+}
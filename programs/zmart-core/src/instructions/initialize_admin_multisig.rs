@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminMultisig, GlobalConfig};
+use crate::error::ErrorCode;
+
+/// Hand protocol admin authority to an M-of-N multisig
+///
+/// Creates the AdminMultisig PDA and sets `GlobalConfig::admin` to its
+/// address in the same instruction. From then on, admin instructions
+/// (approve_proposal, cancel_market, emergency_pause, update_global_config,
+/// propose_admin) run only through propose/approve/execute_admin_action.
+///
+/// If admin was handed back to a single key (ProposeAdmin), the existing
+/// PDA is reused with the new signer set. Its action nonce carries on and
+/// the signer set sequence advances, so actions proposed before the handover
+/// can never execute and can be closed via cancel_admin_action.
+///
+/// # Arguments
+///
+/// * `signers` - Multisig members (1..=MAX_MULTISIG_SIGNERS, no duplicates)
+/// * `threshold` - Approvals required per action (1..=signers.len())
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+/// * `InvalidMultisigConfig` - If signer set or threshold is invalid
+#[derive(Accounts)]
+pub struct InitializeAdminMultisig<'info> {
+    /// Current single-key admin (pays for account creation on first use)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminMultisig::LEN,
+        seeds = [b"admin-multisig"],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for initialize_admin_multisig instruction
+pub fn handler(
+    ctx: Context<InitializeAdminMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let config = &mut ctx.accounts.global_config;
+
+    // A multisig that held admin before always has a signer set
+    if multisig.signer_count == 0 {
+        multisig.action_nonce = 0;
+        multisig.signer_set_seq = 0;
+        multisig.bump = ctx.bumps.multisig;
+    }
+    multisig.set_signers(&signers, threshold)?;
+
+    // Multisig PDA becomes the admin; it can only sign via execute_admin_action
+    let previous_admin = config.admin;
+    config.admin = multisig.key();
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminMultisigInitialized {
+        multisig: multisig.key(),
+        previous_admin,
+        signers,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when admin authority moves to the multisig
+#[event]
+pub struct AdminMultisigInitialized {
+    pub multisig: Pubkey,
+    pub previous_admin: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
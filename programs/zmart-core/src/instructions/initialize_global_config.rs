@@ -7,7 +7,7 @@ use crate::error::ErrorCode;
 /// This instruction creates the GlobalConfig PDA with default settings for:
 /// - Fee structure (3% protocol, 2% resolver, 5% LP)
 /// - Voting thresholds (70% proposal approval, 60% dispute success)
-/// - Time limits (24h minimum resolution delay, 3 days dispute period)
+/// - Time limits (24h minimum resolution delay, 3 days dispute period, 365 days claim period)
 /// - Protocol admin and wallets
 ///
 /// Can only be called once. Subsequent calls will fail with AlreadyInitialized.
@@ -28,7 +28,7 @@ pub struct InitializeGlobalConfig<'info> {
     /// Global configuration PDA (created once)
     ///
     /// Seeds: [b"global-config"]
-    /// Space: GlobalConfig::LEN (198 bytes + 8 discriminator)
+    /// Space: GlobalConfig::LEN (206 bytes) + 8 spare bytes
     #[account(
         init,
         seeds = [b"global-config"],
@@ -55,6 +55,7 @@ pub fn handler(
 
     // Set admin and backend authority
     global_config.admin = ctx.accounts.admin.key();
+    global_config.pending_admin = Pubkey::default();
     global_config.backend_authority = backend_authority;
 
     // Set protocol fee wallet
@@ -88,12 +89,15 @@ pub fn handler(
     // Set minimum resolver reputation (80%)
     global_config.min_resolver_reputation = 8000;
 
+    // Set claim window after finalization (365 days)
+    global_config.claim_period = GlobalConfig::DEFAULT_CLAIM_PERIOD;
+
     // Initialize state
-    global_config.is_paused = false;
+    global_config.paused_operations = 0;
     global_config.bump = ctx.bumps.global_config;
 
-    // Initialize reserved space to zero
-    global_config.reserved = [0; 64];
+    // No guardian until the admin appoints one
+    global_config.guardian = Pubkey::default();
 
     msg!(
         "Global config initialized by admin: {}",
@@ -136,8 +140,10 @@ mod tests {
             min_resolution_delay: 86_400,
             dispute_period: 259_200,
             min_resolver_reputation: 8000,
-            is_paused: false,
-            reserved: [0; 64],
+            paused_operations: 0,
+            claim_period: 31_536_000,
+            pending_admin: Pubkey::default(),
+            guardian: Pubkey::default(),
             bump: 255,
         }
     }
@@ -199,12 +205,12 @@ mod tests {
         let config = create_test_global_config();
 
         // Protocol should not be paused initially
-        assert_eq!(config.is_paused, false);
+        assert_eq!(config.paused_operations, 0);
 
         // Min resolver reputation should be 80%
         assert_eq!(config.min_resolver_reputation, 8000);
 
-        // Reserved space should be zeroed
-        assert_eq!(config.reserved, [0; 64]);
+        // No guardian initially
+        assert_eq!(config.guardian, Pubkey::default());
     }
 }
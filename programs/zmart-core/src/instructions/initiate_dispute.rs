@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use crate::state::{GlobalConfig, MarketAccount, MarketOutcome, MarketState, PAUSE_RESOLVE};
 
 /// Challenge a resolution (RESOLVING → DISPUTED)
 ///
 /// Allows any user to dispute the proposed outcome within the dispute window.
+/// The initiator names the outcome they believe is correct; dispute voters
+/// then decide between it and the proposed outcome.
 /// Resets dispute vote counters and opens community voting.
+///
+/// # Arguments
+/// * `dispute_outcome` - Outcome the initiator claims is correct (must differ from proposed)
 #[derive(Accounts)]
 pub struct InitiateDispute<'info> {
     #[account(
@@ -26,11 +31,14 @@ pub struct InitiateDispute<'info> {
     pub initiator: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<InitiateDispute>) -> Result<()> {
+pub fn handler(ctx: Context<InitiateDispute>, dispute_outcome: MarketOutcome) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let config = &ctx.accounts.global_config;
     let clock = Clock::get()?;
 
+    config.require_not_paused(PAUSE_RESOLVE)?;
+    market.require_not_frozen()?;
+
     // SECURITY FIX (Finding #5): Validate timestamp monotonicity
     // Dispute can only be initiated after resolution is proposed
     require!(
@@ -54,7 +62,15 @@ pub fn handler(ctx: Context<InitiateDispute>) -> Result<()> {
         ErrorCode::AlreadyDisputed
     );
 
+    // Dispute must propose a different outcome (YES/NO/INVALID)
+    let proposed_outcome = market.proposed_outcome.ok_or(ErrorCode::NoResolutionProposed)?;
+    require!(
+        dispute_outcome != proposed_outcome,
+        ErrorCode::InvalidDisputeOutcome
+    );
+
     // Record dispute initiation
+    market.set_dispute_outcome(dispute_outcome);
     market.dispute_initiator = ctx.accounts.initiator.key();
     market.dispute_initiated_at = clock.unix_timestamp;
 
@@ -70,7 +86,8 @@ pub fn handler(ctx: Context<InitiateDispute>) -> Result<()> {
     emit!(DisputeInitiated {
         market_id: market.market_id,
         initiator: market.dispute_initiator,
-        disputed_outcome: market.proposed_outcome,
+        disputed_outcome: proposed_outcome,
+        dispute_outcome,
         timestamp: market.dispute_initiated_at,
     });
 
@@ -82,7 +99,8 @@ pub fn handler(ctx: Context<InitiateDispute>) -> Result<()> {
 pub struct DisputeInitiated {
     pub market_id: [u8; 32],
     pub initiator: Pubkey,
-    pub disputed_outcome: Option<bool>,
+    pub disputed_outcome: MarketOutcome,
+    pub dispute_outcome: MarketOutcome,
     pub timestamp: i64,
 }
 #[cfg(test)]
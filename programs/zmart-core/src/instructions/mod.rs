@@ -16,6 +16,18 @@ pub mod finalize_market;
 // Claim Instructions (Day 6)
 pub mod claim_winnings;
 pub mod withdraw_liquidity;
+pub mod claim_resolver_fees;
+pub mod claim_cancellation_refund;
+pub mod reclaim_market;
+pub mod close_position;
+pub mod close_vote_record;
+pub mod close_market;
+pub mod sweep_unclaimed;
+pub mod claim_many;
+
+// Liquidity Provider Instructions
+pub mod add_liquidity;
+pub mod remove_liquidity;
 
 // Voting Instructions (Phase 1, Week 1)
 pub mod submit_proposal_vote;
@@ -25,8 +37,21 @@ pub mod aggregate_dispute_votes;
 
 // Admin Instructions (Phase 1, Week 3)
 pub mod update_global_config;
+pub mod cancel_config_update;
+pub mod apply_config;
 pub mod emergency_pause;
+pub mod freeze_market;
 pub mod cancel_market;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod update_backend_authority;
+pub mod update_guardian;
+pub mod update_protocol_fee_wallet;
+pub mod initialize_admin_multisig;
+pub mod propose_admin_action;
+pub mod approve_admin_action;
+pub mod execute_admin_action;
+pub mod cancel_admin_action;
 
 // Re-export instruction handlers
 pub use initialize_global_config::*;
@@ -40,10 +65,33 @@ pub use initiate_dispute::*;
 pub use finalize_market::*;
 pub use claim_winnings::*;
 pub use withdraw_liquidity::*;
+pub use claim_resolver_fees::*;
+pub use claim_cancellation_refund::*;
+pub use reclaim_market::*;
+pub use close_position::*;
+pub use close_vote_record::*;
+pub use close_market::*;
+pub use sweep_unclaimed::*;
+pub use claim_many::*;
+pub use add_liquidity::*;
+pub use remove_liquidity::*;
 pub use submit_proposal_vote::*;
 pub use aggregate_proposal_votes::*;
 pub use submit_dispute_vote::*;
 pub use aggregate_dispute_votes::*;
 pub use update_global_config::*;
+pub use cancel_config_update::*;
+pub use apply_config::*;
 pub use emergency_pause::*;
+pub use freeze_market::*;
 pub use cancel_market::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use update_backend_authority::*;
+pub use update_guardian::*;
+pub use update_protocol_fee_wallet::*;
+pub use initialize_admin_multisig::*;
+pub use propose_admin_action::*;
+pub use approve_admin_action::*;
+pub use execute_admin_action::*;
+pub use cancel_admin_action::*;
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::error::ErrorCode;
+
+/// Nominate a new protocol admin (step 1 of 2)
+///
+/// The current admin records `new_admin` as `pending_admin`. Control only moves
+/// once the nominee signs accept_admin, so a mistyped key cannot lock the
+/// protocol out. Proposing `Pubkey::default()` cancels a pending handover.
+///
+/// # Arguments
+///
+/// * `new_admin` - Wallet that must call accept_admin
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// Current protocol admin
+    pub admin: Signer<'info>,
+
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Handler for propose_admin instruction
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    config.pending_admin = new_admin;
+
+    emit!(AdminTransferProposed {
+        current_admin: config.admin,
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Admin transfer proposed to {}", new_admin);
+
+    Ok(())
+}
+
+/// Event emitted when an admin handover is proposed or cancelled
+#[event]
+pub struct AdminTransferProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{AdminAction, AdminMultisig, PendingAdminAction};
+
+/// Propose an admin action for multisig approval
+///
+/// Creates a PendingAdminAction and counts the proposer's approval.
+///
+/// # Arguments
+///
+/// * `action` - Admin operation to run once the threshold is reached
+///
+/// # Errors
+///
+/// * `NotMultisigSigner` - If proposer is not in the signer set
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    /// Multisig signer proposing the action (pays for account creation)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin-multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingAdminAction::LEN,
+        seeds = [
+            b"admin-action",
+            multisig.key().as_ref(),
+            &multisig.action_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for propose_admin_action instruction
+pub fn handler(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let pending_action = &mut ctx.accounts.pending_action;
+    let clock = Clock::get()?;
+
+    let index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+
+    pending_action.multisig = multisig.key();
+    pending_action.proposer = ctx.accounts.proposer.key();
+    pending_action.action = action;
+    pending_action.approvals = 0;
+    pending_action.signer_set_seq = multisig.signer_set_seq;
+    pending_action.created_at = clock.unix_timestamp;
+    pending_action.bump = ctx.bumps.pending_action;
+    pending_action.approve(index)?;
+
+    let nonce = multisig.action_nonce;
+    multisig.action_nonce = nonce.wrapping_add(1);
+
+    emit!(AdminActionProposed {
+        pending_action: pending_action.key(),
+        proposer: pending_action.proposer,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when an admin action is proposed
+#[event]
+pub struct AdminActionProposed {
+    pub pending_action: Pubkey,
+    pub proposer: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState};
+use crate::utils::{max_transferable_amount, transfer_from_pda_with_data};
+
+/// Reclaim the subsidy from a market that never completed
+///
+/// Creator recovers every lamport above the rent-exempt minimum (escrowed
+/// subsidy and dust) and the market is retired as a CLOSED tombstone, which
+/// keeps its rent so the market_id cannot be reused. Allowed when the market:
+/// - is CANCELLED and every trader refund has been claimed, or
+/// - is REJECTED, or
+/// - is a PROPOSED market whose voting window elapsed without reaching
+///   `proposal_approval_threshold` (votes not yet aggregated into REJECTED)
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not the market creator
+/// * `RefundsOutstanding` - If a cancelled market still owes trader refunds
+/// * `LiquidityOutstanding` - If third-party LPs have not removed their liquidity
+/// * `CannotReclaimMarket` - If market is in any other state
+#[derive(Accounts)]
+pub struct ReclaimMarket<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump,
+        has_one = creator @ ErrorCode::Unauthorized
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReclaimMarket>) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let clock = Clock::get()?;
+
+    // Closing the account would hand third-party LPs' deposits to the creator
+    require!(
+        market.total_lp_shares == market.creator_lp_shares,
+        ErrorCode::LiquidityOutstanding
+    );
+
+    match market.state {
+        MarketState::Cancelled => {
+            // Traders are refunded before the creator gets anything back
+            require!(market.outstanding_refunds() == 0, ErrorCode::RefundsOutstanding);
+        }
+        // Rejected proposals never traded, nothing is owed to anyone else
+        MarketState::Rejected => {}
+        _ => {
+            require!(
+                market.proposal_rejected(
+                    ctx.accounts.global_config.proposal_approval_threshold,
+                    clock.unix_timestamp,
+                ),
+                ErrorCode::CannotReclaimMarket
+            );
+        }
+    }
+
+    let amount = max_transferable_amount(&market.to_account_info())?;
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        amount,
+    )?;
+    market.retire()?;
+
+    emit!(MarketReclaimed {
+        market_id: market.market_id,
+        creator: market.creator,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketReclaimed {
+    pub market_id: [u8; 32],
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, LiquidityPosition, MarketAccount, MarketLock, MarketState, PAUSE_LIQUIDITY};
+use crate::utils::transfer_from_pda_with_data;
+
+/// Burn LP shares and withdraw the liquidity they represent
+///
+/// Before activation (PROPOSED or APPROVED) shares redeem at par: the
+/// provider gets their deposit back and b shrinks with the pool. Liquidity
+/// is locked while the market trades and resolves. Once the market is
+/// FINALIZED, CANCELLED or REJECTED, shares redeem pro-rata for what is left
+/// of the pool after the resolver's unclaimed fees and outstanding trader
+/// refunds are set aside. Winners of a YES/NO market are paid from the
+/// subsidy too, so there redemption waits until every position is settled
+/// or the claim window has closed, leaving the LP fees plus the pool share
+/// of the market maker's own initial shares.
+///
+/// # Arguments
+/// * `lp_shares` - LP shares to burn
+///
+/// # Errors
+/// * `InsufficientShares` - If the position holds fewer than `lp_shares`
+/// * `InvalidMarketState` - If the market is trading or resolving
+/// * `WinningsOutstanding` - If winners of a YES/NO market can still claim
+/// * `InsufficientLiquidity` - If the shares redeem for nothing
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"lp-position", market.key().as_ref(), provider.key().as_ref()],
+        bump = lp_position.bump,
+        has_one = provider @ ErrorCode::Unauthorized,
+        has_one = market @ ErrorCode::InvalidMarketId
+    )]
+    pub lp_position: Account<'info, LiquidityPosition>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveLiquidity>, lp_shares: u64) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let lp_position = &mut ctx.accounts.lp_position;
+
+    ctx.accounts.global_config.require_not_paused(PAUSE_LIQUIDITY)?;
+    market.require_not_frozen()?;
+
+    require!(
+        lp_shares > 0 && lp_shares <= lp_position.lp_shares,
+        ErrorCode::InsufficientShares
+    );
+
+    let amount = match market.state {
+        MarketState::Proposed | MarketState::Approved => {
+            market.remove_liquidity_at_par(lp_shares)?
+        }
+        MarketState::Finalized | MarketState::Cancelled | MarketState::Rejected => {
+            require!(
+                market.winners_settled(
+                    ctx.accounts.global_config.claim_window(),
+                    Clock::get()?.unix_timestamp,
+                ),
+                ErrorCode::WinningsOutstanding
+            );
+            let account_info = market.to_account_info();
+            let rent_reserve = Rent::get()?.minimum_balance(account_info.data_len());
+            let withdrawable = market.lp_withdrawable(account_info.lamports(), rent_reserve);
+            market.redeem_lp_shares(lp_shares, withdrawable)?
+        }
+        _ => return err!(ErrorCode::InvalidMarketState),
+    };
+
+    require!(amount > 0, ErrorCode::InsufficientLiquidity);
+
+    lp_position.lp_shares -= lp_shares;
+    lp_position.total_withdrawn = lp_position.total_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::OverflowError)?;
+
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.provider.to_account_info(),
+        amount,
+    )?;
+
+    emit!(LiquidityRemoved {
+        market_id: market.market_id,
+        provider: ctx.accounts.provider.key(),
+        amount,
+        lp_shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub market_id: [u8; 32],
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub lp_shares: u64,
+    pub timestamp: i64,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState};
+use crate::state::{GlobalConfig, MarketAccount, MarketOutcome, MarketState, PAUSE_RESOLVE};
 
 /// Propose market resolution (ACTIVE → RESOLVING)
 ///
@@ -8,7 +8,7 @@ use crate::state::{GlobalConfig, MarketAccount, MarketState};
 /// the proposed outcome. Any user can resolve (reputation checked off-chain).
 ///
 /// # Arguments
-/// * `proposed_outcome` - Proposed outcome (true=YES, false=NO)
+/// * `proposed_outcome` - Proposed outcome (YES, NO, or INVALID for ambiguous questions)
 /// * `ipfs_evidence_hash` - IPFS CID with resolution evidence (46 bytes)
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
@@ -33,13 +33,16 @@ pub struct ResolveMarket<'info> {
 
 pub fn handler(
     ctx: Context<ResolveMarket>,
-    proposed_outcome: bool,
+    proposed_outcome: MarketOutcome,
     ipfs_evidence_hash: [u8; 46],
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
 
+    ctx.accounts.global_config.require_not_paused(PAUSE_RESOLVE)?;
+    market.require_not_frozen()?;
+
     // SECURITY FIX (Finding #5): Verify market hasn't already been resolved
     require!(market.proposed_outcome.is_none(), ErrorCode::AlreadyResolved);
 
@@ -101,7 +104,7 @@ pub fn handler(
 pub struct MarketResolved {
     pub market_id: [u8; 32],
     pub resolver: Pubkey,
-    pub outcome: bool,
+    pub outcome: MarketOutcome,
     pub evidence_hash: [u8; 46],
     pub timestamp: i64,
 }
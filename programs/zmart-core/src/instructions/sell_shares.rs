@@ -1,14 +1,14 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{GlobalConfig, MarketAccount, MarketState, UserPosition};
-use crate::math::lmsr;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState, UserPosition, PAUSE_SELL};
 use crate::utils::{transfer_with_rent_check, calculate_fees_accurate};
 use super::buy_shares::MIN_TRADE_AMOUNT;
 
 /// Sell YES or NO shares back to the pool
 ///
 /// Users specify how many shares to sell and receive proceeds calculated by
-/// LMSR formula. Fees are deducted from proceeds: 3% protocol + 2% resolver + 5% LP = 10% total.
+/// the market's pricing curve. Fees are deducted from proceeds: 3% protocol + 2% resolver + 5% LP = 10% total.
+/// Parimutuel stakes are final and cannot be sold (`SellNotSupported`).
 ///
 /// # Arguments
 /// * `outcome` - true for YES, false for NO
@@ -17,7 +17,7 @@ use super::buy_shares::MIN_TRADE_AMOUNT;
 ///
 /// # State Changes
 /// * Market: shares_yes/shares_no decreased, liquidity decreased by payout
-/// * Position: shares decreased, trades_count++
+/// * Position: shares decreased, net_contribution decreased by payout, trades_count++
 ///
 /// # Fees
 /// Same 10% structure as buy, deducted from proceeds
@@ -66,22 +66,24 @@ pub fn handler(
     shares_to_sell: u64,
     min_proceeds: u64,
 ) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
     let position = &mut ctx.accounts.position;
     let config = &ctx.accounts.global_config;
 
     // Check if protocol is paused (emergency pause active)
-    require!(!config.is_paused, ErrorCode::ProtocolPaused);
+    config.require_not_paused(PAUSE_SELL)?;
+    market.require_not_frozen()?;
 
     // Check user has enough shares
     let user_shares = if outcome { position.shares_yes } else { position.shares_no };
     require!(user_shares >= shares_to_sell, ErrorCode::InsufficientShares);
 
     // Calculate proceeds from selling shares (before fees)
-    let proceeds_before_fees = lmsr::calculate_sell_proceeds(
+    let curve = market.pricing_curve();
+    let proceeds_before_fees = curve.sell_proceeds(
         market.shares_yes,
         market.shares_no,
-        market.b_parameter,
         outcome,
         shares_to_sell,
     )?;
@@ -96,11 +98,12 @@ pub fn handler(
     // SECURITY FIX (Finding #6): Use accurate fee calculation to prevent value leakage
     // Old approach calculated fees individually, losing precision on each division
     // New approach calculates total fees first, then splits proportionally
+    let fee_schedule = market.fee_schedule(config);
     let fees = calculate_fees_accurate(
         proceeds_before_fees,
-        config.protocol_fee_bps,
-        config.resolver_reward_bps,
-        config.liquidity_provider_fee_bps,
+        fee_schedule.protocol_fee_bps,
+        fee_schedule.resolver_reward_bps,
+        fee_schedule.liquidity_provider_fee_bps,
     )?;
 
     let net_proceeds = proceeds_before_fees
@@ -149,8 +152,20 @@ pub fn handler(
     position.trades_count = position.trades_count.checked_add(1).ok_or(ErrorCode::OverflowError)?;
     position.last_trade_at = Clock::get()?.unix_timestamp;
 
-    // SECURITY FIX (Finding #8): Lock market before lamport transfers (reentrancy protection)
-    market.lock()?;
+    // Lamports leaving the pool reduce the seller's net contribution (floored at 0,
+    // profits beyond the contribution don't go negative). Market total moves by the
+    // same amount so it always equals the sum over positions.
+    let contribution_reduction = position.net_contribution.min(total_payout);
+    position.net_contribution = position.net_contribution
+        .checked_sub(contribution_reduction)
+        .ok_or(ErrorCode::UnderflowError)?;
+    market.total_net_contribution = market.total_net_contribution
+        .checked_sub(contribution_reduction)
+        .ok_or(ErrorCode::UnderflowError)?;
+
+    // SECURITY: Creator loss must stay within b * ln(2) after every trade,
+    // so an LMSR precision bug cannot pay sellers out of the subsidy
+    market.verify_bounded_loss()?;
 
     // SECURITY FIX (Finding #2): Transfer net proceeds to user with rent check
     // Ensures market account maintains rent exemption after transfer
@@ -170,9 +185,6 @@ pub fn handler(
         &ctx.accounts.system_program.to_account_info(),
     )?;
 
-    // SECURITY FIX (Finding #8): Unlock market after transfers complete
-    market.unlock();
-
     // Emit event
     emit!(SharesSold {
         market_id: market.market_id,
@@ -180,7 +192,7 @@ pub fn handler(
         outcome,
         shares: shares_to_sell,
         proceeds: net_proceeds,
-        new_price_yes: lmsr::calculate_yes_price(market.shares_yes, market.shares_no, market.b_parameter)?,
+        new_price_yes: curve.price(market.shares_yes, market.shares_no, true)?,
         timestamp: position.last_trade_at,
     });
 
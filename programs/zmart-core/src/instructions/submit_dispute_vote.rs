@@ -59,6 +59,13 @@ pub struct SubmitDisputeVote<'info> {
 
     /// System program for account creation
     pub system_program: Program<'info, System>,
+
+    /// Global configuration (pause flags)
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
 }
 
 /// Handler for submit_dispute_vote instruction
@@ -67,6 +74,9 @@ pub struct SubmitDisputeVote<'info> {
 /// for the backend to index. The backend will aggregate all votes off-chain
 /// and eventually call aggregate_dispute_votes to determine if dispute succeeds.
 pub fn handler(ctx: Context<SubmitDisputeVote>, vote: bool) -> Result<()> {
+    ctx.accounts.global_config.require_not_paused(PAUSE_VOTE)?;
+    ctx.accounts.market.require_not_frozen()?;
+
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
@@ -58,6 +58,13 @@ pub struct SubmitProposalVote<'info> {
 
     /// System program for account creation
     pub system_program: Program<'info, System>,
+
+    /// Global configuration (pause flags)
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
 }
 
 /// Handler for submit_proposal_vote instruction
@@ -66,6 +73,9 @@ pub struct SubmitProposalVote<'info> {
 /// for the backend to index. The backend will aggregate all votes off-chain
 /// and eventually call approve_proposal when the 70% threshold is met.
 pub fn handler(ctx: Context<SubmitProposalVote>, vote: bool) -> Result<()> {
+    ctx.accounts.global_config.require_not_paused(PAUSE_VOTE)?;
+    ctx.accounts.market.require_not_frozen()?;
+
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, MarketAccount, MarketLock, MarketState};
+use crate::utils::{max_transferable_amount, transfer_from_pda_with_data};
+
+/// Sweep unclaimed funds to the protocol treasury after the claim window
+///
+/// Once the market's `claim_deadline` (fixed at finalization) has passed,
+/// claims are closed and the admin moves everything above the rent-exempt minimum to
+/// `protocol_fee_wallet`. The market account stays open as an on-chain record
+/// and can later be closed via close_market.
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+/// * `InvalidMarketState` - If market is not FINALIZED
+/// * `ClaimPeriodNotEnded` - If the claim window is still open
+/// * `InsufficientLiquidity` - If nothing is left to sweep
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    /// Protocol admin
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", &market.market_id],
+        bump = market.bump,
+        constraint = market.state == MarketState::Finalized @ ErrorCode::InvalidMarketState
+    )]
+    pub market: Account<'info, MarketAccount>,
+
+    /// Protocol fee wallet (validated against global_config)
+    /// CHECK: Validated with constraint
+    #[account(
+        mut,
+        constraint = protocol_fee_wallet.key() == global_config.protocol_fee_wallet @ ErrorCode::Unauthorized
+    )]
+    pub protocol_fee_wallet: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<SweepUnclaimed>) -> Result<()> {
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+    let clock = Clock::get()?;
+
+    require!(
+        market.claim_period_ended(ctx.accounts.global_config.claim_window(), clock.unix_timestamp),
+        ErrorCode::ClaimPeriodNotEnded
+    );
+
+    // Everything above rent-exempt minimum is unclaimed at this point
+    let amount = max_transferable_amount(&market.to_account_info())?;
+    require!(amount > 0, ErrorCode::InsufficientLiquidity);
+
+    transfer_from_pda_with_data(
+        &market.to_account_info(),
+        &ctx.accounts.protocol_fee_wallet.to_account_info(),
+        amount,
+    )?;
+
+    // Nothing is owed to anyone anymore
+    market.current_liquidity = 0;
+    market.accumulated_lp_fees = 0;
+    market.seed_legacy_refunds();
+    market.total_net_contribution = 0;
+    market.resolver_fees_claimed = true;
+
+    emit!(UnclaimedFundsSwept {
+        market_id: market.market_id,
+        amount,
+        unclaimed_positions: market.open_positions,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Record of funds forfeited by positions that never claimed
+#[event]
+pub struct UnclaimedFundsSwept {
+    pub market_id: [u8; 32],
+    pub amount: u64,
+    pub unclaimed_positions: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::error::ErrorCode;
+
+/// Rotate the backend authority used for vote aggregation
+///
+/// Lets the admin replace a compromised or lost backend key without
+/// redeploying.
+///
+/// # Arguments
+///
+/// * `new_backend_authority` - Key that will sign aggregation instructions
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+#[derive(Accounts)]
+pub struct UpdateBackendAuthority<'info> {
+    /// Protocol admin
+    pub admin: Signer<'info>,
+
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Handler for update_backend_authority instruction
+pub fn handler(ctx: Context<UpdateBackendAuthority>, new_backend_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    let previous_backend_authority = config.backend_authority;
+    config.backend_authority = new_backend_authority;
+
+    emit!(BackendAuthorityUpdated {
+        previous_backend_authority,
+        new_backend_authority,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when backend authority is rotated
+#[event]
+pub struct BackendAuthorityUpdated {
+    pub previous_backend_authority: Pubkey,
+    pub new_backend_authority: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
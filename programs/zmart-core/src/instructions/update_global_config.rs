@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::GlobalConfig;
+use crate::state::{GlobalConfig, PendingConfig, CONFIG_TIMELOCK};
 use crate::error::ErrorCode;
 
-/// Update global protocol configuration parameters
+/// Queue an update to global protocol configuration parameters
 ///
 /// Allows protocol admin to modify fee percentages, voting thresholds, and other
-/// configuration parameters without redeploying the program. Critical for mainnet
-/// operations and emergency parameter adjustments.
+/// configuration parameters without redeploying the program. The change is
+/// validated and stored in the PendingConfig PDA, and takes effect only when
+/// apply_config is called after `CONFIG_TIMELOCK`, giving users time to react.
+/// Queueing replaces any change already pending and restarts the timelock.
 ///
 /// # Arguments
 ///
@@ -15,6 +17,10 @@ use crate::error::ErrorCode;
 /// * `liquidity_provider_fee_bps` - LP fee in basis points (0-10000)
 /// * `proposal_approval_threshold` - Proposal approval threshold (0-10000)
 /// * `dispute_success_threshold` - Dispute success threshold (0-10000)
+/// * `min_resolution_delay` - Optional minimum resolution delay in seconds
+/// * `dispute_period` - Optional dispute period duration in seconds
+/// * `claim_period` - Optional claim window after finalization in seconds
+///   (at least 30 days; applies only to markets finalized afterwards)
 ///
 /// # Errors
 ///
@@ -27,14 +33,29 @@ pub struct UpdateGlobalConfig<'info> {
     /// Protocol admin with configuration authority
     pub admin: Signer<'info>,
 
-    /// Global configuration account to update
+    /// Global configuration account the change will apply to
     #[account(
-        mut,
         seeds = [b"global-config"],
         bump = global_config.bump,
         constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
     )]
     pub global_config: Account<'info, GlobalConfig>,
+
+    /// Queued config change (created on first use)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingConfig::LEN,
+        seeds = [b"pending-config"],
+        bump
+    )]
+    pub pending_config: Account<'info, PendingConfig>,
+
+    /// Pays rent for the PendingConfig account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Handler for update_global_config instruction
@@ -47,9 +68,8 @@ pub fn handler(
     dispute_success_threshold: u16,
     min_resolution_delay: Option<i64>,
     dispute_period: Option<i64>,
+    claim_period: Option<i64>,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.global_config;
-
     // Validate fee structure: total fees must not exceed 100% (10000 bps)
     let total_fee = (protocol_fee_bps as u32)
         .checked_add(resolver_reward_bps as u32)
@@ -79,53 +99,71 @@ pub fn handler(
     if let Some(period) = dispute_period {
         require!(period > 0, ErrorCode::InvalidTimeLimit);
     }
+    if let Some(period) = claim_period {
+        require!(period >= GlobalConfig::MIN_CLAIM_PERIOD, ErrorCode::InvalidTimeLimit);
+    }
 
-    // Update configuration fields
-    config.protocol_fee_bps = protocol_fee_bps;
-    config.resolver_reward_bps = resolver_reward_bps;
-    config.liquidity_provider_fee_bps = liquidity_provider_fee_bps;
-    config.proposal_approval_threshold = proposal_approval_threshold;
-    config.dispute_success_threshold = dispute_success_threshold;
+    let now = Clock::get()?.unix_timestamp;
+    let effective_at = now
+        .checked_add(CONFIG_TIMELOCK)
+        .ok_or(ErrorCode::OverflowError)?;
 
-    // Update time parameters if provided
-    if let Some(delay) = min_resolution_delay {
-        config.min_resolution_delay = delay;
-    }
-    if let Some(period) = dispute_period {
-        config.dispute_period = period;
-    }
+    // Queue the change
+    let pending = &mut ctx.accounts.pending_config;
+    pending.is_pending = true;
+    pending.protocol_fee_bps = protocol_fee_bps;
+    pending.resolver_reward_bps = resolver_reward_bps;
+    pending.liquidity_provider_fee_bps = liquidity_provider_fee_bps;
+    pending.proposal_approval_threshold = proposal_approval_threshold;
+    pending.dispute_success_threshold = dispute_success_threshold;
+    pending.min_resolution_delay = min_resolution_delay;
+    pending.dispute_period = dispute_period;
+    pending.claim_period = claim_period;
+    pending.proposed_at = now;
+    pending.effective_at = effective_at;
+    pending.bump = ctx.bumps.pending_config;
 
-    // Emit event with updated configuration
-    emit!(ConfigUpdated {
+    emit!(ConfigUpdateQueued {
         protocol_fee_bps,
         resolver_reward_bps,
         liquidity_provider_fee_bps,
         proposal_approval_threshold,
         dispute_success_threshold,
-        min_resolution_delay: config.min_resolution_delay,
-        dispute_period: config.dispute_period,
-        timestamp: Clock::get()?.unix_timestamp,
+        min_resolution_delay,
+        dispute_period,
+        claim_period,
+        effective_at,
+        timestamp: now,
     });
 
     msg!(
-        "Global config updated: protocol_fee={}%, resolver_reward={}%, lp_fee={}%, proposal_threshold={}%, dispute_threshold={}%",
+        "Global config update queued: protocol_fee={}%, resolver_reward={}%, lp_fee={}%, proposal_threshold={}%, dispute_threshold={}%, effective_at={}",
         protocol_fee_bps / 100,
         resolver_reward_bps / 100,
         liquidity_provider_fee_bps / 100,
         proposal_approval_threshold / 100,
-        dispute_success_threshold / 100
+        dispute_success_threshold / 100,
+        effective_at
     );
 
-    if let Some(delay) = min_resolution_delay {
-        msg!("  min_resolution_delay: {} seconds", delay);
-    }
-    if let Some(period) = dispute_period {
-        msg!("  dispute_period: {} seconds", period);
-    }
-
     Ok(())
 }
 
+/// Event emitted when a configuration change is queued
+#[event]
+pub struct ConfigUpdateQueued {
+    pub protocol_fee_bps: u16,
+    pub resolver_reward_bps: u16,
+    pub liquidity_provider_fee_bps: u16,
+    pub proposal_approval_threshold: u16,
+    pub dispute_success_threshold: u16,
+    pub min_resolution_delay: Option<i64>,
+    pub dispute_period: Option<i64>,
+    pub claim_period: Option<i64>,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
 /// Event emitted when configuration is updated
 #[event]
 pub struct ConfigUpdated {
@@ -136,6 +174,7 @@ pub struct ConfigUpdated {
     pub dispute_success_threshold: u16,
     pub min_resolution_delay: i64,
     pub dispute_period: i64,
+    pub claim_period: i64,
     pub timestamp: i64,
 }
 
@@ -157,8 +196,10 @@ mod tests {
             min_resolution_delay: 86400, // 24 hours
             dispute_period: 259200, // 3 days
             min_resolver_reputation: 8000, // 80%
-            is_paused: false,
-            reserved: [0; 64],
+            paused_operations: 0,
+            claim_period: 31_536_000, // 365 days
+            pending_admin: Pubkey::default(),
+            guardian: Pubkey::default(),
             bump: 255,
         }
     }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::error::ErrorCode;
+
+/// Appoint, rotate or remove the guardian
+///
+/// The guardian can pause protocol operations and freeze markets during an
+/// incident without holding the admin key; only the admin can undo either.
+///
+/// # Arguments
+///
+/// * `new_guardian` - Guardian key (Pubkey::default() removes the guardian)
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+#[derive(Accounts)]
+pub struct UpdateGuardian<'info> {
+    /// Protocol admin
+    pub admin: Signer<'info>,
+
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Handler for update_guardian instruction
+pub fn handler(ctx: Context<UpdateGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    let previous_guardian = config.guardian;
+    config.guardian = new_guardian;
+
+    emit!(GuardianUpdated {
+        previous_guardian,
+        new_guardian,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when the guardian changes
+#[event]
+pub struct GuardianUpdated {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::error::ErrorCode;
+
+/// Rotate the wallet receiving protocol fees and swept funds
+///
+/// # Errors
+///
+/// * `Unauthorized` - If signer is not admin
+#[derive(Accounts)]
+pub struct UpdateProtocolFeeWallet<'info> {
+    /// Protocol admin
+    pub admin: Signer<'info>,
+
+    /// Global configuration account to update
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// New wallet receiving protocol fees
+    /// CHECK: Any account can be protocol wallet, validated by admin
+    pub protocol_fee_wallet: UncheckedAccount<'info>,
+}
+
+/// Handler for update_protocol_fee_wallet instruction
+pub fn handler(ctx: Context<UpdateProtocolFeeWallet>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    let previous_wallet = config.protocol_fee_wallet;
+    config.protocol_fee_wallet = ctx.accounts.protocol_fee_wallet.key();
+
+    emit!(ProtocolFeeWalletUpdated {
+        previous_wallet,
+        new_wallet: config.protocol_fee_wallet,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when protocol fee wallet is rotated
+#[event]
+pub struct ProtocolFeeWalletUpdated {
+    pub previous_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
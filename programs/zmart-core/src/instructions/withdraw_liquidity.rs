@@ -1,18 +1,43 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
-use crate::state::{MarketAccount, MarketState};
+use crate::state::{CurveType, GlobalConfig, MarketAccount, MarketLock, MarketState};
 
-/// Withdraw remaining liquidity after market finalized
+/// Withdraw remaining liquidity after market finalized or cancelled
 ///
-/// Creator withdraws all remaining funds (liquidity + accumulated LP fees)
-/// while preserving rent reserve to keep account alive.
+/// Creator redeems their LP shares for their pro-rata cut of the remaining
+/// funds (liquidity + accumulated LP fees) while preserving rent reserve to
+/// keep account alive; third-party LPs redeem theirs via remove_liquidity.
+/// Markets created before LP shares have no third-party LPs, so the creator
+/// withdraws everything. Resolver fees not yet paid out via
+/// claim_resolver_fees are left in the market, as are trader refunds still
+/// owed on CANCELLED or INVALID markets, so LPs only recover the subsidy
+/// that remains after traders are made whole. On YES/NO markets winners are
+/// paid from the subsidy as well, so withdrawal waits until every position
+/// is settled or the claim window has closed; what is left then is the LP
+/// fees plus the pool share of the market maker's own initial shares.
+///
+/// Parimutuel markets have no subsidy: the whole pool belongs to bettors,
+/// so there is nothing for the creator to withdraw.
+///
+/// # Errors
+///
+/// * `LiquidityNotSupported` - If the market is parimutuel
+/// * `WinningsOutstanding` - If winners of a YES/NO market can still claim
 #[derive(Accounts)]
 pub struct WithdrawLiquidity<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         seeds = [b"market", &market.market_id],
         bump = market.bump,
-        constraint = market.state == MarketState::Finalized @ ErrorCode::InvalidMarketState,
+        constraint = (market.state == MarketState::Finalized
+            || market.state == MarketState::Cancelled) @ ErrorCode::InvalidMarketState,
+        constraint = market.curve_type != CurveType::Parimutuel @ ErrorCode::LiquidityNotSupported,
         has_one = creator @ ErrorCode::Unauthorized
     )]
     pub market: Account<'info, MarketAccount>,
@@ -22,7 +47,17 @@ pub struct WithdrawLiquidity<'info> {
 }
 
 pub fn handler(ctx: Context<WithdrawLiquidity>) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+    // SECURITY FIX (Finding #8): Reentrancy lock, released on every exit path
+    let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+
+    // Winners are paid from the same pool, so they go first
+    require!(
+        market.winners_settled(
+            ctx.accounts.global_config.claim_window(),
+            Clock::get()?.unix_timestamp,
+        ),
+        ErrorCode::WinningsOutstanding
+    );
 
     // Calculate withdrawable amount (balance - rent reserve - safety margin)
     let remaining_balance = market.to_account_info().lamports();
@@ -31,8 +66,20 @@ pub fn handler(ctx: Context<WithdrawLiquidity>) -> Result<()> {
     let reserved_for_rent = rent.minimum_balance(account_info.data_len());
 
     // Add 10,000 lamports safety margin (~0.00001 SOL) to ensure account stays rent-exempt
-    let safe_reserve = reserved_for_rent.saturating_add(10_000);
-    let withdrawable = remaining_balance.saturating_sub(safe_reserve);
+    // and keep the resolver's unclaimed fees and outstanding trader refunds in the market
+    let pool = market.lp_withdrawable(remaining_balance, reserved_for_rent);
+
+    let withdrawable = if market.total_lp_shares == 0 {
+        // Markets created before LP shares: the creator owns the whole pool
+        market.current_liquidity = 0;
+        market.accumulated_lp_fees = 0;
+        pool
+    } else {
+        let creator_lp_shares = market.creator_lp_shares;
+        require!(creator_lp_shares > 0, ErrorCode::InsufficientLiquidity);
+        market.creator_lp_shares = 0;
+        market.redeem_lp_shares(creator_lp_shares, pool)?
+    };
 
     require!(withdrawable > 0, ErrorCode::InsufficientLiquidity);
 
@@ -40,10 +87,6 @@ pub fn handler(ctx: Context<WithdrawLiquidity>) -> Result<()> {
     **market.to_account_info().try_borrow_mut_lamports()? -= withdrawable;
     **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += withdrawable;
 
-    // Zero out liquidity and LP fees
-    market.current_liquidity = 0;
-    market.accumulated_lp_fees = 0;
-
     // Emit event
     emit!(LiquidityWithdrawn {
         market_id: market.market_id,
@@ -102,4 +145,16 @@ mod tests {
 
         assert_eq!(withdrawable, 1); // Can withdraw 1 lamport
     }
+
+    #[test]
+    fn test_withdrawable_reserves_cancellation_refunds() {
+        let balance = 12_000_000u64;
+        let rent = 2_000_000u64;
+        let outstanding_refunds = 7_000_000u64; // Traders' net contributions
+        let safe_reserve = rent.saturating_add(10_000).saturating_add(outstanding_refunds);
+        let withdrawable = balance.saturating_sub(safe_reserve);
+
+        assert_eq!(withdrawable, 2_990_000); // Only the remaining subsidy
+        assert!(balance - withdrawable >= rent + outstanding_refunds);
+    }
 }
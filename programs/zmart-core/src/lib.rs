@@ -47,8 +47,8 @@ pub mod zmart_core {
     /// # Arguments
     ///
     /// * `market_id` - Unique identifier for this market (32 bytes, used in PDA seeds)
-    /// * `b_parameter` - LMSR liquidity sensitivity parameter
-    /// * `initial_liquidity` - Starting liquidity in lamports
+    /// * `b_parameter` - LMSR liquidity sensitivity parameter (MIN_B..=MAX_B)
+    /// * `initial_liquidity` - Starting liquidity in lamports (at least b * ln(2))
     /// * `ipfs_question_hash` - IPFS CID for market question (46 bytes)
     pub fn create_market(
         ctx: Context<CreateMarket>,
@@ -66,6 +66,63 @@ pub mod zmart_core {
         )
     }
 
+    /// Create a market from a subsidy amount, deriving the LMSR b parameter
+    ///
+    /// Uses the deepest b the subsidy can back (b = subsidy / ln(2)), so the
+    /// subsidy exactly covers the worst-case loss.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_id` - Unique identifier for this market (32 bytes, used in PDA seeds)
+    /// * `subsidy` - Liquidity deposited by the creator in lamports
+    /// * `ipfs_question_hash` - IPFS CID for market question (46 bytes)
+    pub fn create_market_from_subsidy(
+        ctx: Context<CreateMarket>,
+        market_id: [u8; 32],
+        subsidy: u64,
+        ipfs_question_hash: [u8; 46],
+    ) -> Result<()> {
+        create_market::handler_from_subsidy(ctx, market_id, subsidy, ipfs_question_hash)
+    }
+
+    /// Create a liquidity-sensitive LMSR (LS-LMSR) market
+    ///
+    /// The market's b grows with the shares outstanding (b = alpha * Σq), so
+    /// thin markets stay responsive and busy ones get deeper.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_id` - Unique identifier for this market (32 bytes, used in PDA seeds)
+    /// * `alpha` - Liquidity sensitivity (MIN_ALPHA..=MAX_ALPHA, fixed-point)
+    /// * `initial_liquidity` - Subsidy in lamports, which covers the worst-case loss
+    /// * `ipfs_question_hash` - IPFS CID for market question (46 bytes)
+    pub fn create_ls_lmsr_market(
+        ctx: Context<CreateMarket>,
+        market_id: [u8; 32],
+        alpha: u64,
+        initial_liquidity: u64,
+        ipfs_question_hash: [u8; 46],
+    ) -> Result<()> {
+        create_market::handler_ls_lmsr(ctx, market_id, alpha, initial_liquidity, ipfs_question_hash)
+    }
+
+    /// Create a parimutuel (pool betting) market
+    ///
+    /// Stakes buy YES or NO shares 1:1 with no price discovery, and winners
+    /// split the pool via claim_winnings. No subsidy is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_id` - Unique identifier for this market (32 bytes, used in PDA seeds)
+    /// * `ipfs_question_hash` - IPFS CID for market question (46 bytes)
+    pub fn create_parimutuel_market(
+        ctx: Context<CreateMarket>,
+        market_id: [u8; 32],
+        ipfs_question_hash: [u8; 46],
+    ) -> Result<()> {
+        create_market::handler_parimutuel(ctx, market_id, ipfs_question_hash)
+    }
+
     /// Approve a market proposal (admin only)
     ///
     /// Transitions a market from PROPOSED → APPROVED after validating
@@ -79,11 +136,13 @@ pub mod zmart_core {
     /// Activate an approved market (admin or creator)
     ///
     /// Transitions a market from APPROVED → ACTIVE, enabling trading.
-    /// Can be called by either admin or market creator.
+    /// Can be called by either admin or market creator. With `snapshot_fees`,
+    /// the market keeps the fee schedule in effect at activation.
     pub fn activate_market(
         ctx: Context<ActivateMarket>,
+        snapshot_fees: bool,
     ) -> Result<()> {
-        activate_market::handler(ctx)
+        activate_market::handler(ctx, snapshot_fees)
     }
 
     // ============================================================================
@@ -137,11 +196,11 @@ pub mod zmart_core {
     ///
     /// # Arguments
     ///
-    /// * `proposed_outcome` - true for YES, false for NO
+    /// * `proposed_outcome` - YES, NO, or INVALID (ambiguous/unresolvable question)
     /// * `ipfs_evidence_hash` - IPFS CID with resolution evidence (46 bytes)
     pub fn resolve_market(
         ctx: Context<ResolveMarket>,
-        proposed_outcome: bool,
+        proposed_outcome: MarketOutcome,
         ipfs_evidence_hash: [u8; 46],
     ) -> Result<()> {
         resolve_market::handler(ctx, proposed_outcome, ipfs_evidence_hash)
@@ -151,16 +210,21 @@ pub mod zmart_core {
     ///
     /// Any user can dispute proposed outcome during dispute window.
     /// Opens community voting via off-chain aggregation.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispute_outcome` - Outcome the initiator claims is correct (YES, NO, or INVALID)
     pub fn initiate_dispute(
         ctx: Context<InitiateDispute>,
+        dispute_outcome: MarketOutcome,
     ) -> Result<()> {
-        initiate_dispute::handler(ctx)
+        initiate_dispute::handler(ctx, dispute_outcome)
     }
 
     /// Set final outcome (RESOLVING/DISPUTED → FINALIZED)
     ///
     /// Backend authority finalizes market after vote aggregation.
-    /// For disputed markets: if ≥60% agree to flip, flip the outcome.
+    /// For disputed markets: if ≥60% agree, the disputed outcome replaces the proposed one.
     ///
     /// # Arguments
     ///
@@ -185,23 +249,126 @@ pub mod zmart_core {
     /// - NO outcome: Only NO holders win
     /// - INVALID outcome: All holders refunded
     ///
-    /// First claimer pays resolver their accumulated fees
+    /// Resolver fees are paid separately via claim_resolver_fees
     pub fn claim_winnings(
         ctx: Context<ClaimWinnings>,
     ) -> Result<()> {
         claim_winnings::handler(ctx)
     }
 
-    /// Withdraw remaining liquidity after market finalized
+    /// Claim winnings from many finalized markets in one transaction
+    ///
+    /// Remaining accounts are (market, position) pairs. Same payout rules as
+    /// claim_winnings; stops cleanly when the compute budget runs low.
+    pub fn claim_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>,
+    ) -> Result<()> {
+        claim_many::handler(ctx)
+    }
+
+    /// Claim accumulated resolver fees after market finalized
     ///
-    /// Creator withdraws remaining pool funds + LP fees while
-    /// preserving rent reserve.
+    /// Resolver withdraws the resolver fee pool for YES/NO outcomes.
+    /// For INVALID outcomes the pool is forfeited and refunded to traders.
+    pub fn claim_resolver_fees(
+        ctx: Context<ClaimResolverFees>,
+    ) -> Result<()> {
+        claim_resolver_fees::handler(ctx)
+    }
+
+    /// Claim refund after market cancelled
+    ///
+    /// Traders recover their net contribution, pro-rata if the market
+    /// cannot cover all outstanding refunds.
+    pub fn claim_cancellation_refund(
+        ctx: Context<ClaimCancellationRefund>,
+    ) -> Result<()> {
+        claim_cancellation_refund::handler(ctx)
+    }
+
+    /// Withdraw remaining liquidity after market finalized or cancelled
+    ///
+    /// Creator redeems their LP shares for their cut of the remaining pool
+    /// funds + LP fees while preserving rent reserve and any outstanding
+    /// trader refunds. YES/NO markets must have settled their winners first.
     pub fn withdraw_liquidity(
         ctx: Context<WithdrawLiquidity>,
     ) -> Result<()> {
         withdraw_liquidity::handler(ctx)
     }
 
+    /// Deposit liquidity into a market before trading for LP shares
+    ///
+    /// b scales with the pool so prices stay at 50/50 and the deposit
+    /// covers its share of the worst-case loss.
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        amount: u64,
+    ) -> Result<()> {
+        add_liquidity::handler(ctx, amount)
+    }
+
+    /// Burn LP shares for their share of the pool
+    ///
+    /// At par before activation, pro-rata of what remains (LP fees
+    /// included) once the market is finalized, cancelled or rejected and,
+    /// for YES/NO outcomes, winners have been settled.
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_shares: u64,
+    ) -> Result<()> {
+        remove_liquidity::handler(ctx, lp_shares)
+    }
+
+    /// Retire a cancelled or rejected market and return its subsidy to the creator
+    ///
+    /// The market stays as a CLOSED tombstone holding its rent, so its
+    /// market_id cannot be reused. Cancelled markets must have paid out all
+    /// trader refunds first.
+    /// Rejected proposals qualify once their voting window has elapsed,
+    /// whether or not the votes were aggregated into REJECTED state.
+    pub fn reclaim_market(
+        ctx: Context<ReclaimMarket>,
+    ) -> Result<()> {
+        reclaim_market::handler(ctx)
+    }
+
+    /// Close a settled position and return its rent to the user
+    ///
+    /// Allowed after a claim/refund, when the position never owed anything,
+    /// or when it holds no winning shares in a finalized market.
+    pub fn close_position(
+        ctx: Context<ClosePosition>,
+    ) -> Result<()> {
+        close_position::handler(ctx)
+    }
+
+    /// Close a vote record once its voting phase is over and return its rent
+    pub fn close_vote_record(
+        ctx: Context<CloseVoteRecord>,
+    ) -> Result<()> {
+        close_vote_record::handler(ctx)
+    }
+
+    /// Close a finalized market and sweep its remainder to the treasury
+    ///
+    /// Allowed once all LP shares are redeemed and all positions are settled,
+    /// or after the claim deadline. The market stays as a CLOSED tombstone.
+    pub fn close_market(
+        ctx: Context<CloseMarket>,
+    ) -> Result<()> {
+        close_market::handler(ctx)
+    }
+
+    /// Sweep unclaimed funds to the treasury once the claim period has ended
+    ///
+    /// Admin only. Emits the swept amount and number of unclaimed positions.
+    pub fn sweep_unclaimed(
+        ctx: Context<SweepUnclaimed>,
+    ) -> Result<()> {
+        sweep_unclaimed::handler(ctx)
+    }
+
     // ============================================================================
     // Voting Instructions (Phase 1, Week 1)
     // ============================================================================
@@ -241,9 +408,10 @@ pub mod zmart_core {
     ///
     /// * Records vote counts in MarketAccount
     /// * Calculates approval percentage
-    /// * If >= 70% likes: transitions to APPROVED state
-    /// * If < 70% likes: stays in PROPOSED (can re-aggregate)
-    /// * Emits ProposalAggregated event
+    /// * If >= 70% likes: stays in PROPOSED awaiting admin approve_proposal
+    /// * If < 70% likes: stays in PROPOSED (can re-aggregate) until the voting
+    ///   window elapses, then transitions to REJECTED
+    /// * Emits ProposalAggregated event (and ProposalRejected on rejection)
     ///
     /// # Errors
     ///
@@ -320,7 +488,9 @@ pub mod zmart_core {
     /// Update global protocol configuration parameters
     ///
     /// Allows admin to modify fee percentages, voting thresholds, and other
-    /// configuration parameters without redeploying the program.
+    /// configuration parameters without redeploying the program. The change
+    /// is queued in the PendingConfig PDA and applied via apply_config after
+    /// the 48-hour timelock.
     ///
     /// # Arguments
     ///
@@ -331,6 +501,8 @@ pub mod zmart_core {
     /// * `dispute_success_threshold` - Dispute success threshold (0-10000)
     /// * `min_resolution_delay` - Optional minimum resolution delay in seconds
     /// * `dispute_period` - Optional dispute period duration in seconds
+    /// * `claim_period` - Optional claim window after finalization in seconds
+    ///   (at least 30 days; applies only to markets finalized afterwards)
     pub fn update_global_config(
         ctx: Context<UpdateGlobalConfig>,
         protocol_fee_bps: u16,
@@ -340,6 +512,7 @@ pub mod zmart_core {
         dispute_success_threshold: u16,
         min_resolution_delay: Option<i64>,
         dispute_period: Option<i64>,
+        claim_period: Option<i64>,
     ) -> Result<()> {
         update_global_config::handler(
             ctx,
@@ -350,28 +523,146 @@ pub mod zmart_core {
             dispute_success_threshold,
             min_resolution_delay,
             dispute_period,
+            claim_period,
         )
     }
 
-    /// Toggle protocol pause state (pause/unpause trading)
+    /// Cancel a queued config update (admin only)
+    pub fn cancel_config_update(
+        ctx: Context<CancelConfigUpdate>,
+    ) -> Result<()> {
+        cancel_config_update::handler(ctx)
+    }
+
+    /// Apply a queued config update once its timelock has passed
+    ///
+    /// Permissionless, so a queued change cannot be held back indefinitely.
+    pub fn apply_config(
+        ctx: Context<ApplyConfig>,
+    ) -> Result<()> {
+        apply_config::handler(ctx)
+    }
+
+    /// Set which protocol operations are paused (admin or guardian)
     ///
-    /// Allows admin to pause all trading operations in case of critical bugs,
-    /// exploits, or market instability. Calling when running pauses protocol.
-    /// Calling when paused unpauses protocol. Voting and resolution continue.
+    /// Create, buy, sell, claim, vote and resolve are paused independently
+    /// via PAUSE_* flags. Sets the full pause state (not a toggle), so a
+    /// retried transaction cannot unpause. Pass 0 to resume everything.
+    /// The guardian can only add flags; clearing them requires the admin.
     pub fn emergency_pause(
         ctx: Context<EmergencyPause>,
+        paused_operations: u8,
     ) -> Result<()> {
-        emergency_pause::handler(ctx)
+        emergency_pause::handler(ctx, paused_operations)
+    }
+
+    /// Freeze or unfreeze a single market (guardian may only freeze)
+    ///
+    /// A frozen market rejects trading, claims, voting and resolution
+    /// regardless of the protocol pause flags.
+    pub fn freeze_market(
+        ctx: Context<FreezeMarket>,
+        frozen: bool,
+    ) -> Result<()> {
+        freeze_market::handler(ctx, frozen)
     }
 
     /// Cancel a market and transition to CANCELLED state
     ///
     /// Allows admin to cancel markets that are invalid or fraudulent.
-    /// Only works for PROPOSED or APPROVED markets (cannot cancel active/resolving).
-    /// Sets market to CANCELLED state. Refunds handled by separate instruction.
+    /// Works for any non-terminal market, including active/resolving/disputed.
+    /// Sets market to CANCELLED state. Refunds handled by claim_cancellation_refund.
     pub fn cancel_market(
         ctx: Context<CancelMarket>,
     ) -> Result<()> {
         cancel_market::handler(ctx)
     }
+
+    /// Nominate a new protocol admin (step 1 of 2)
+    ///
+    /// The nominee must call accept_admin to take over. Proposing
+    /// `Pubkey::default()` cancels a pending handover.
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept a pending admin nomination (step 2 of 2)
+    pub fn accept_admin(
+        ctx: Context<AcceptAdmin>,
+    ) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    /// Rotate the backend authority used for vote aggregation
+    pub fn update_backend_authority(
+        ctx: Context<UpdateBackendAuthority>,
+        new_backend_authority: Pubkey,
+    ) -> Result<()> {
+        update_backend_authority::handler(ctx, new_backend_authority)
+    }
+
+    /// Appoint, rotate or remove the guardian (pause-only role)
+    pub fn update_guardian(
+        ctx: Context<UpdateGuardian>,
+        new_guardian: Pubkey,
+    ) -> Result<()> {
+        update_guardian::handler(ctx, new_guardian)
+    }
+
+    /// Rotate the wallet receiving protocol fees
+    pub fn update_protocol_fee_wallet(
+        ctx: Context<UpdateProtocolFeeWallet>,
+    ) -> Result<()> {
+        update_protocol_fee_wallet::handler(ctx)
+    }
+
+    // ============================================================================
+    // Multisig Admin Instructions
+    // ============================================================================
+
+    /// Hand admin authority to an M-of-N multisig PDA
+    ///
+    /// After this, admin instructions only run through execute_admin_action.
+    /// Reuses the existing multisig if admin was handed back to a single key.
+    pub fn initialize_admin_multisig(
+        ctx: Context<InitializeAdminMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        initialize_admin_multisig::handler(ctx, signers, threshold)
+    }
+
+    /// Propose an admin action for multisig approval (counts as one approval)
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action: AdminAction,
+    ) -> Result<()> {
+        propose_admin_action::handler(ctx, action)
+    }
+
+    /// Approve a pending admin action
+    pub fn approve_admin_action(
+        ctx: Context<ApproveAdminAction>,
+    ) -> Result<()> {
+        approve_admin_action::handler(ctx)
+    }
+
+    /// Execute an admin action once the multisig threshold is reached
+    ///
+    /// Market-targeted actions take the market as the first remaining account.
+    pub fn execute_admin_action<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteAdminAction<'info>>,
+    ) -> Result<()> {
+        execute_admin_action::handler(ctx)
+    }
+
+    /// Close a pending admin action (proposer, or anyone once it is stale)
+    pub fn cancel_admin_action(
+        ctx: Context<CancelAdminAction>,
+    ) -> Result<()> {
+        cancel_admin_action::handler(ctx)
+    }
 }
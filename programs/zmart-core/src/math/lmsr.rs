@@ -1,10 +1,9 @@
 // LMSR (Logarithmic Market Scoring Rule) - Production Implementation
 //
 // This module implements the complete LMSR algorithm with:
-// - Fixed-point exponential and logarithm approximations
-// - Numerically stable cost function (log-sum-exp trick)
-// - Price calculations using softmax formulation
-// - Binary search for share calculation from target cost
+// - Fixed-point exponential and logarithm (range reduction + series at 18 decimals)
+// - Cost and price functions in terms of q_yes − q_no (any share volume)
+// - Closed-form share calculation from target cost (binary search fallback)
 // - Bounded loss calculation
 //
 // All formulas verified against CORE_LOGIC_INVARIANTS.md Section 1
@@ -23,6 +22,10 @@ pub const LN_2: u64 = 693_147_180; // 0.693147180 in 9-decimal fixed-point
 /// e^20 ≈ 485,165,195 (safe within u64 range with 9 decimals)
 pub const MAX_EXP: u64 = 20 * PRECISION;
 
+/// Exponent beyond which e^(-x) rounds to zero
+/// e^-22 ≈ 2.8e-10, below half of the 9-decimal ulp
+const EXP_NEGATIVE_CUTOFF: u64 = 22 * PRECISION;
+
 /// Minimum b parameter to prevent extreme price sensitivity
 /// 100 SOL minimum ensures reasonable market behavior
 pub const MIN_B: u64 = 100 * PRECISION;
@@ -31,12 +34,15 @@ pub const MIN_B: u64 = 100 * PRECISION;
 // Core LMSR Functions
 // ============================================================================
 
-/// Calculate LMSR cost function using log-sum-exp trick for numerical stability
+/// Calculate LMSR cost function in terms of the share difference
 ///
 /// Formula: C(q_yes, q_no) = b * ln(e^(q_yes/b) + e^(q_no/b))
 ///
-/// The log-sum-exp trick prevents overflow:
-/// ln(e^x + e^y) = max(x,y) + ln(1 + e^(-|x-y|))
+/// Factoring out the larger side leaves only the difference in the exponent:
+/// C = max(q_yes, q_no) + b * ln(1 + e^(-|q_yes - q_no|/b))
+///
+/// so the cost is defined for any share volume, and any difference: once
+/// e^(-|d|/b) is below an ulp the correction term is exactly zero.
 ///
 /// # Arguments
 /// * `q_yes` - Outstanding YES shares (fixed-point, 9 decimals)
@@ -47,13 +53,22 @@ pub const MIN_B: u64 = 100 * PRECISION;
 /// * `Result<u64>` - Total cost to create current market state (lamports)
 pub fn cost_function(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
     require!(b >= MIN_B, ErrorCode::InvalidBParameter);
+    log_sum_exp_cost(q_yes, q_no, b)
+}
 
-    let x = fixed_div(q_yes, b)?; // q_yes / b
-    let y = fixed_div(q_no, b)?;  // q_no / b
+/// b * ln(e^(q_yes/b) + e^(q_no/b)) for any b > 0
+///
+/// Shared with the LS-LMSR, whose b varies with the share totals.
+pub(super) fn log_sum_exp_cost(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
+    let (max_q, diff) = if q_yes >= q_no {
+        (q_yes, q_yes - q_no)
+    } else {
+        (q_no, q_no - q_yes)
+    };
 
-    // Apply log-sum-exp trick for numerical stability
-    let log_sum = log_sum_exp(x, y)?;
-    let cost = fixed_mul(b, log_sum)?;
+    // b * ln(1 + e^(-diff/b)), between 0 and b * ln(2)
+    let spread = fixed_mul(b, ln_one_plus_exp_negative(fixed_div(diff, b)?)?)?;
+    let cost = max_q.checked_add(spread).ok_or(ErrorCode::OverflowError)?;
 
     Ok(cost)
 }
@@ -62,7 +77,10 @@ pub fn cost_function(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
 ///
 /// Formula: P(YES) = e^(q_yes/b) / (e^(q_yes/b) + e^(q_no/b))
 ///
-/// Uses numerically stable softmax to prevent overflow.
+/// Depends only on d = |q_yes - q_no|: the favored side is priced at
+/// 1 / (1 + e^(-d/b)) and the other side gets the remainder. Only a negative
+/// exponent is evaluated, so the price saturates at 0 or 1 instead of
+/// failing for lopsided markets.
 /// Price is always in range [0, 1] (fixed-point).
 ///
 /// # Arguments
@@ -75,24 +93,22 @@ pub fn cost_function(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
 pub fn calculate_yes_price(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
     require!(b >= MIN_B, ErrorCode::InvalidBParameter);
 
-    // Use softmax formulation for numerical stability
+    let favored_price = favored_side_price(q_yes.abs_diff(q_no), b)?;
+
     if q_yes >= q_no {
-        // YES is favored: P = e^(diff/b) / (e^(diff/b) + 1)
-        let diff = q_yes.checked_sub(q_no).ok_or(ErrorCode::UnderflowError)?;
-        let ratio = fixed_div(diff, b)?;
-        let exp_ratio = fixed_exp(ratio)?;
-        let denominator = exp_ratio.checked_add(PRECISION).ok_or(ErrorCode::OverflowError)?;
-        fixed_div(exp_ratio, denominator)
+        Ok(favored_price)
     } else {
-        // NO is favored: P = 1 / (1 + e^(diff/b))
-        let diff = q_no.checked_sub(q_yes).ok_or(ErrorCode::UnderflowError)?;
-        let ratio = fixed_div(diff, b)?;
-        let exp_ratio = fixed_exp(ratio)?;
-        let denominator = PRECISION.checked_add(exp_ratio).ok_or(ErrorCode::OverflowError)?;
-        fixed_div(PRECISION, denominator)
+        Ok(PRECISION - favored_price)
     }
 }
 
+/// Softmax price of the side ahead by `diff` shares: 1 / (1 + e^(-diff/b))
+pub(super) fn favored_side_price(diff: u64, b: u64) -> Result<u64> {
+    let exp_neg = fixed_exp_negative(fixed_div(diff, b)?)?;
+    let denominator = PRECISION.checked_add(exp_neg).ok_or(ErrorCode::OverflowError)?;
+    fixed_div(PRECISION, denominator)
+}
+
 /// Calculate NO outcome price (complementary to YES price)
 ///
 /// Formula: P(NO) = 1 - P(YES)
@@ -116,10 +132,12 @@ pub fn calculate_no_price(q_yes: u64, q_no: u64, b: u64) -> Result<u64> {
 // Trading Operations
 // ============================================================================
 
-/// Binary search to find shares that match target cost
+/// Find the number of shares that costs `target_cost`
 ///
-/// Given a target cost, find the number of shares that costs approximately that amount.
-/// Uses binary search over share quantity to find best match within tolerance.
+/// Uses the closed-form inverse of the cost function (see
+/// `shares_for_cost_closed_form`), which needs two exponentials and one
+/// logarithm instead of up to 50 cost function evaluations. Falls back to
+/// the binary search when the closed form is out of the exp domain.
 ///
 /// # Arguments
 /// * `current_q_yes` - Current YES shares in market
@@ -136,15 +154,83 @@ pub fn shares_for_cost(
     b: u64,
     outcome: bool,
     target_cost: u64,
+) -> Result<u64> {
+    require!(b >= MIN_B, ErrorCode::InvalidBParameter);
+
+    let (q_self, q_other) = if outcome {
+        (current_q_yes, current_q_no)
+    } else {
+        (current_q_no, current_q_yes)
+    };
+
+    match shares_for_cost_closed_form(q_self, q_other, b, target_cost) {
+        Ok(shares) => Ok(shares),
+        Err(_) => shares_for_cost_search(current_q_yes, current_q_no, b, outcome, target_cost),
+    }
+}
+
+/// Closed-form inverse of the binary LMSR cost function
+///
+/// Buying Δq of an outcome with `q_self` shares for cost t satisfies
+///   e^((q_self + Δq)/b) + e^(q_other/b) = (e^(q_self/b) + e^(q_other/b)) · e^(t/b)
+/// Dividing both sides by e^(q_self/b):
+///   Δq = b · ln(e^(t/b) + e^((q_other − q_self)/b) · (e^(t/b) − 1))
+/// Only differences are exponentiated, so this stays in range whenever
+/// t/b and (q_other − q_self)/b are at most MAX_EXP.
+///
+/// # Errors
+/// Returns `ExponentTooLarge` or an overflow error when the inputs are
+/// outside that domain; shares_for_cost then falls back to the search.
+fn shares_for_cost_closed_form(
+    q_self: u64,
+    q_other: u64,
+    b: u64,
+    target_cost: u64,
+) -> Result<u64> {
+    let exp_cost = fixed_exp(fixed_div(target_cost, b)?)?;
+
+    // e^((q_other − q_self)/b); zero once q_self leads by enough
+    let weight = if q_other <= q_self {
+        fixed_exp_negative(fixed_div(q_self - q_other, b)?)?
+    } else {
+        fixed_exp(fixed_div(q_other - q_self, b)?)?
+    };
+
+    let growth = fixed_mul(weight, exp_cost - PRECISION)?;
+    let ratio = exp_cost.checked_add(growth).ok_or(ErrorCode::OverflowError)?;
+
+    fixed_mul(b, fixed_ln(ratio)?)
+}
+
+/// Binary search to find shares that match target cost
+///
+/// Given a target cost, find the number of shares that costs approximately that amount.
+/// Uses binary search over share quantity to find best match within tolerance.
+/// Fallback for shares_for_cost when the closed form is out of range.
+/// The tolerance is reached within 50 halvings for any u64 bound.
+fn shares_for_cost_search(
+    current_q_yes: u64,
+    current_q_no: u64,
+    b: u64,
+    outcome: bool,
+    target_cost: u64,
 ) -> Result<u64> {
     let cost_before = cost_function(current_q_yes, current_q_no, b)?;
 
+    let (q_self, q_other) = if outcome {
+        (current_q_yes, current_q_no)
+    } else {
+        (current_q_no, current_q_yes)
+    };
+
     let mut low: u64 = 0;
-    // Upper bound: MAX_EXP * b to prevent exponent overflow
-    // This ensures q/b <= MAX_EXP (safe for e^x calculation)
-    // MAX_EXP = 20 * PRECISION, so max_shares = 20 * b
-    let max_safe_shares = 20u64.checked_mul(b).ok_or(ErrorCode::OverflowError)?;
-    let mut high: u64 = max_safe_shares;
+    // Upper bound: max(q) ≤ C(q) ≤ max(q) + b * ln(2), so buying
+    // target + (q_other − q_self)⁺ + b * ln(2) shares costs at least the target
+    let mut high: u64 = target_cost
+        .saturating_add(q_other.saturating_sub(q_self))
+        .saturating_add(calculate_max_loss(b))
+        .saturating_add(1)
+        .min(u64::MAX - q_self);
     let tolerance: u64 = PRECISION / 1000; // 0.001 tolerance
 
     // Binary search with max 50 iterations
@@ -181,7 +267,8 @@ pub fn shares_for_cost(
 /// Calculate cost to buy shares and return (cost, shares) tuple
 ///
 /// This is the primary function used by buy_shares instruction.
-/// Given a target cost, it finds how many shares that buys using binary search.
+/// Given a target cost, it finds how many shares that buys (closed form, with
+/// binary search as fallback).
 ///
 /// # Arguments
 /// * `current_q_yes` - Current YES shares in market
@@ -282,12 +369,66 @@ fn fixed_div(a: u64, b: u64) -> Result<u64> {
     Ok(result as u64)
 }
 
-/// Approximate e^x using Padé (2,2) approximation
+/// Internal precision for exp/ln: 18 decimals (1.0 = 10^18)
 ///
-/// Formula: e^x ≈ (1 + x/2 + x²/12) / (1 - x/2 + x²/12)
+/// Intermediate results carry 9 more digits than the public fixed-point
+/// format, so truncation in the series stays far below one output ulp.
+const WIDE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// ln(2) with 18 decimals
+const LN_2_WIDE: u128 = 693_147_180_559_945_309;
+
+/// √2 with 18 decimals
+const SQRT_2_WIDE: u128 = 1_414_213_562_373_095_049;
+
+/// Ratio between the internal and public precision
+const WIDE_TO_PRECISION: u128 = WIDE_PRECISION / PRECISION as u128;
+
+/// Round an 18-decimal value to the 9-decimal fixed-point format
+fn narrow(value: u128) -> Result<u64> {
+    let rounded = value
+        .checked_add(WIDE_TO_PRECISION / 2)
+        .ok_or(ErrorCode::OverflowError)?
+        / WIDE_TO_PRECISION;
+    u64::try_from(rounded).map_err(|_| ErrorCode::OverflowError.into())
+}
+
+/// e^x with 18 decimals, split as (e^r, k) where e^x = e^r * 2^k
 ///
-/// Accurate for x ∈ [0, 20] with error < 0.001%
-/// This is a rational approximation that's more accurate than Taylor series.
+/// Range reduction: x = k * ln(2) + r with r ∈ [0, ln 2), then e^r is
+/// summed as a Taylor series until terms vanish at 18 decimals (at most
+/// 19 terms, since ln(2)^19 / 19! < 10^-18).
+///
+/// e^r < 2 * 10^18 < 2^61, so callers must bound x such that k < 67 for
+/// e^r << k to fit in u128.
+fn exp_wide(x: u64) -> (u128, u32) {
+    #[cfg(test)]
+    tests::count_evaluation();
+
+    let x_wide = (x as u128) * WIDE_TO_PRECISION;
+    let k = x_wide / LN_2_WIDE;
+    let r = x_wide - k * LN_2_WIDE;
+
+    let mut sum = WIDE_PRECISION;
+    let mut term = WIDE_PRECISION;
+    let mut n: u128 = 1;
+    while term > 0 {
+        term = term * r / (n * WIDE_PRECISION);
+        sum += term;
+        n += 1;
+    }
+
+    (sum, k as u32)
+}
+
+/// Calculate e^x in fixed-point
+///
+/// Range reduction by ln(2) with an 18-decimal Taylor series (see
+/// `exp_wide`), rounded to nearest.
+///
+/// Max error over [0, MAX_EXP]: |fixed_exp(x) − e^x| ≤ 1 ulp + 10^-15 · e^x.
+/// The relative term only exceeds an ulp for large x (e^20 ≈ 4.85e8 carries
+/// 18 significant digits, more than the 18-decimal intermediate resolves).
 ///
 /// # Arguments
 /// * `x` - Exponent in fixed-point (must be ≤ MAX_EXP = 20)
@@ -297,52 +438,20 @@ fn fixed_div(a: u64, b: u64) -> Result<u64> {
 fn fixed_exp(x: u64) -> Result<u64> {
     require!(x <= MAX_EXP, ErrorCode::ExponentTooLarge);
 
-    // Calculate x² for Padé approximation
-    let x2 = fixed_mul(x, x)?;
-
-    // Numerator: 1 + x/2 + x²/12
-    let num = PRECISION
-        .checked_add(x / 2).ok_or(ErrorCode::OverflowError)?
-        .checked_add(x2 / 12).ok_or(ErrorCode::OverflowError)?;
-
-    // Denominator: 1 - x/2 + x²/12
-    // CRITICAL FIX: When x >= 2*PRECISION, x/2 >= PRECISION, causing underflow
-    // Rewrite to avoid subtraction: denom = PRECISION - x/2 + x²/12
-    //                                     = PRECISION + x²/12 - x/2
-    // Check if x/2 can be safely subtracted
-    let x_half = x / 2;
-    let x2_term = x2 / 12;
-
-    // If x_half > PRECISION, we need to restructure the calculation
-    // denom = (PRECISION + x²/12) - x/2
-    let denom = if x_half <= PRECISION {
-        // Safe subtraction: 1 - x/2 is positive
-        PRECISION
-            .checked_sub(x_half).ok_or(ErrorCode::UnderflowError)?
-            .checked_add(x2_term).ok_or(ErrorCode::OverflowError)?
-    } else {
-        // x_half > PRECISION, so we need: (x²/12) - (x/2 - PRECISION)
-        // = x²/12 - x/2 + PRECISION
-        // But x/2 might still be > x²/12 + PRECISION for large x
-        // Let's compute: PRECISION + x²/12, then check if >= x_half
-        let sum = PRECISION.checked_add(x2_term).ok_or(ErrorCode::OverflowError)?;
-
-        if sum >= x_half {
-            sum.checked_sub(x_half).ok_or(ErrorCode::UnderflowError)?
-        } else {
-            // This would give negative denominator, which means e^x approximation breaks down
-            // For very large x, use alternative: e^x ≈ e^10 * e^(x-10) recursively
-            // Or just return a very large value as upper bound
-            return Ok(u64::MAX); // Conservative fallback
-        }
-    };
-
-    fixed_div(num, denom)
+    // k ≤ MAX_EXP / ln(2) < 29
+    let (exp_r, k) = exp_wide(x);
+    narrow(exp_r << k)
 }
 
-/// Calculate e^(-x) = 1 / e^x
+/// Calculate e^(-x) in fixed-point
+///
+/// Used by the cost and price functions, which only ever exponentiate the
+/// negated share difference. Computed as 1 / (e^r * 2^k) at 18 decimals
+/// rather than inverting the rounded fixed_exp, so small results keep full
+/// precision.
 ///
-/// Used in log-sum-exp trick for numerical stability.
+/// Defined for every x: beyond EXP_NEGATIVE_CUTOFF (22) the result rounds
+/// to 0. Max error: 1 ulp.
 ///
 /// # Arguments
 /// * `x` - Positive exponent in fixed-point
@@ -350,110 +459,89 @@ fn fixed_exp(x: u64) -> Result<u64> {
 /// # Returns
 /// * `Result<u64>` - e^(-x) in fixed-point
 fn fixed_exp_negative(x: u64) -> Result<u64> {
-    let exp_x = fixed_exp(x)?;
-    fixed_div(PRECISION, exp_x)
+    if x > EXP_NEGATIVE_CUTOFF {
+        return Ok(0);
+    }
+
+    // k ≤ EXP_NEGATIVE_CUTOFF / ln(2) < 32
+    let (exp_r, k) = exp_wide(x);
+    let inverse = WIDE_PRECISION * WIDE_PRECISION / (exp_r << k);
+    narrow(inverse)
 }
 
-/// Approximate ln(x) using Taylor series with range reduction
+/// Calculate ln(x) in fixed-point for x ≥ 1
 ///
 /// Algorithm:
-/// 1. Reduce x to range [0.5, 2.0] by factoring out powers of 2
-/// 2. Apply Taylor series: ln(x) = 2 * (y + y³/3 + y⁵/5 + ...)
-///    where y = (x - 1) / (x + 1)
-/// 3. Adjust result: ln(x) = ln(x_reduced) + k * ln(2)
+/// 1. Widen to 18 decimals and factor out powers of 2 so that
+///    x = m * 2^k with m ∈ [1/√2, √2)
+/// 2. Sum ln(m) = 2 * atanh(y) = 2 * (y + y³/3 + y⁵/5 + ...) with
+///    y = (m − 1) / (m + 1), |y| ≤ 0.172, until terms vanish
+///    (at most 12 terms)
+/// 3. ln(x) = ln(m) + k * ln(2), rounded to nearest
+///
+/// Max error over [1, u64::MAX / 10^9]: 1 ulp.
 ///
 /// # Arguments
-/// * `x` - Value in fixed-point (must be > 0)
+/// * `x` - Value in fixed-point (must be ≥ PRECISION, since the result is unsigned)
 ///
 /// # Returns
 /// * `Result<u64>` - ln(x) in fixed-point
 fn fixed_ln(x: u64) -> Result<u64> {
     require!(x > 0, ErrorCode::InvalidInput);
-
-    // Special case: ln(1) = 0
-    if x == PRECISION {
-        return Ok(0);
+    require!(x >= PRECISION, ErrorCode::InvalidLogarithm);
+
+    #[cfg(test)]
+    tests::count_evaluation();
+
+    // Range reduction: x = m * 2^k, m ∈ [1/√2, √2) at 18 decimals
+    let x_wide = (x as u128) * WIDE_TO_PRECISION;
+    // x ≥ 1, so the bit-length difference is non-negative
+    let mut k = WIDE_PRECISION.leading_zeros() - x_wide.leading_zeros();
+    let mut m = x_wide >> k;
+    while m >= SQRT_2_WIDE {
+        m >>= 1;
+        k += 1;
     }
-
-    // Range reduction: factor out powers of 2
-    let mut x_reduced = x;
-    let mut exponent: i64 = 0;
-
-    while x_reduced >= 2 * PRECISION {
-        x_reduced /= 2;
-        exponent += 1;
-    }
-    while x_reduced < PRECISION / 2 {
-        x_reduced *= 2;
-        exponent -= 1;
+    while m * 2 < SQRT_2_WIDE {
+        m <<= 1;
+        k -= 1;
     }
 
-    // Calculate y = (x - 1) / (x + 1) for Taylor series
-    let y = if x_reduced >= PRECISION {
-        let num = x_reduced.checked_sub(PRECISION).ok_or(ErrorCode::UnderflowError)?;
-        let denom = x_reduced.checked_add(PRECISION).ok_or(ErrorCode::OverflowError)?;
-        fixed_div(num, denom)?
-    } else {
-        // For x < 1, use inverse: y = (1/x - 1) / (1/x + 1)
-        let inv = fixed_div(PRECISION, x_reduced)?;
-        let num = inv.checked_sub(PRECISION).ok_or(ErrorCode::UnderflowError)?;
-        let denom = inv.checked_add(PRECISION).ok_or(ErrorCode::OverflowError)?;
-        fixed_div(num, denom)?
-    };
-
-    // Calculate powers of y
-    let y2 = fixed_mul(y, y)?;
-    let y3 = fixed_mul(y2, y)?;
-    let y5 = fixed_mul(y3, y2)?;
-
-    // Taylor series: 2 * (y + y³/3 + y⁵/5)
-    let series = 2u64
-        .checked_mul(
-            y.checked_add(y3 / 3).ok_or(ErrorCode::OverflowError)?
-             .checked_add(y5 / 5).ok_or(ErrorCode::OverflowError)?
-        ).ok_or(ErrorCode::OverflowError)?;
-
-    // Adjust for range reduction: ln(x) = series + exponent * ln(2)
-    let adjustment = (exponent as i128)
-        .checked_mul(LN_2 as i128)
-        .ok_or(ErrorCode::OverflowError)?;
-
-    let result = if adjustment >= 0 {
-        series.checked_add(adjustment as u64).ok_or(ErrorCode::OverflowError)?
-    } else {
-        series.checked_sub((-adjustment) as u64).ok_or(ErrorCode::UnderflowError)?
-    };
+    // atanh series in signed arithmetic (y < 0 when m < 1)
+    let scale = WIDE_PRECISION as i128;
+    let m = m as i128;
+    let y = (m - scale) * scale / (m + scale);
+    let y2 = y * y / scale;
+
+    let mut power = y;
+    let mut series = 0i128;
+    let mut n: i128 = 1;
+    while power != 0 {
+        series += power / n;
+        power = power * y2 / scale;
+        n += 2;
+    }
 
-    Ok(result)
+    // k ≥ 0 and |series| < ln(√2)/2, so the sum is non-negative
+    let ln_wide = 2 * series + (k as i128) * (LN_2_WIDE as i128);
+    narrow(ln_wide.max(0) as u128)
 }
 
-/// Numerically stable log-sum-exp: ln(e^x + e^y)
-///
-/// Formula: ln(e^x + e^y) = max(x,y) + ln(1 + e^(-|x-y|))
+/// Log-sum-exp correction term: ln(1 + e^(-d))
 ///
-/// This prevents overflow when x or y are large by factoring out the maximum.
+/// ln(e^x + e^y) = max(x,y) + ln(1 + e^(-|x-y|)), so this is the only part
+/// of the cost function that depends on the outcome balance. It lies in
+/// [0, ln(2)] and is exactly 0 once e^(-d) rounds to zero.
 ///
 /// # Arguments
-/// * `x` - First value in fixed-point
-/// * `y` - Second value in fixed-point
+/// * `d` - Absolute difference |x - y| in fixed-point
 ///
 /// # Returns
-/// * `Result<u64>` - ln(e^x + e^y) in fixed-point
-fn log_sum_exp(x: u64, y: u64) -> Result<u64> {
-    let (max_val, diff) = if x >= y {
-        (x, x.checked_sub(y).ok_or(ErrorCode::UnderflowError)?)
-    } else {
-        (y, y.checked_sub(x).ok_or(ErrorCode::UnderflowError)?)
-    };
-
-    // Calculate ln(1 + e^(-diff))
-    let exp_neg_diff = fixed_exp_negative(diff)?;
+/// * `Result<u64>` - ln(1 + e^(-d)) in fixed-point
+pub(super) fn ln_one_plus_exp_negative(d: u64) -> Result<u64> {
+    let exp_neg_diff = fixed_exp_negative(d)?;
     let one_plus_exp = PRECISION.checked_add(exp_neg_diff).ok_or(ErrorCode::OverflowError)?;
-    let ln_term = fixed_ln(one_plus_exp)?;
-
-    // Result = max + ln(1 + e^(-diff))
-    let result = max_val.checked_add(ln_term).ok_or(ErrorCode::OverflowError)?;
-    Ok(result)
+    fixed_ln(one_plus_exp)
 }
 
 // ============================================================================
@@ -548,6 +636,192 @@ mod tests {
         assert!(error < PRECISION / 100); // < 1% error
     }
 
+    // ------------------------------------------------------------------
+    // Iteration count: exp/ln evaluations per trade
+    // ------------------------------------------------------------------
+
+    thread_local! {
+        /// Series evaluations (exp_wide and fixed_ln) made on this thread
+        static EVALUATIONS: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Record one exp or ln series evaluation
+    pub(super) fn count_evaluation() {
+        EVALUATIONS.with(|count| count.set(count.get() + 1));
+    }
+
+    /// Number of exp/ln series evaluations `f` makes
+    fn evaluations<T>(f: impl FnOnce() -> T) -> u64 {
+        EVALUATIONS.with(|count| count.set(0));
+        f();
+        EVALUATIONS.with(|count| count.get())
+    }
+
+    #[test]
+    fn test_closed_form_matches_search() {
+        let b = 1000 * PRECISION;
+        let states = [(0, 0), (b, 0), (0, b), (5 * b, 2 * b), (b / 1000, b / 1000)];
+        let costs = [crate::instructions::buy_shares::MIN_TRADE_AMOUNT, PRECISION, 50 * PRECISION, 500 * PRECISION, 2_000 * PRECISION];
+
+        for &(q_yes, q_no) in &states {
+            for &cost in &costs {
+                for outcome in [true, false] {
+                    let (q_self, q_other) = if outcome { (q_yes, q_no) } else { (q_no, q_yes) };
+                    let closed = shares_for_cost_closed_form(q_self, q_other, b, cost).unwrap();
+                    let search = shares_for_cost_search(q_yes, q_no, b, outcome, cost).unwrap();
+
+                    // Search stops within its 0.001-share tolerance
+                    assert!(
+                        closed.abs_diff(search) <= PRECISION / 1000 + 1,
+                        "q=({}, {}) cost={} outcome={}: closed {} vs search {}",
+                        q_yes, q_no, cost, outcome, closed, search
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_closed_form_cost_round_trip() {
+        // Buying the closed-form share amount costs the target within a few lamports
+        let b = 500 * PRECISION;
+        for (q_yes, q_no, cost) in [(0, 0, 10 * PRECISION), (3 * b, b, 123_456_789), (b, 4 * b, 75 * PRECISION)] {
+            let (actual, _) = calculate_buy_cost(q_yes, q_no, b, true, cost).unwrap();
+            assert!(actual.abs_diff(cost) <= 1_000, "target {} got {}", cost, actual);
+        }
+    }
+
+    #[test]
+    fn test_closed_form_falls_back_out_of_range() {
+        let b = 100 * PRECISION;
+
+        // Other side leads by more than MAX_EXP: closed form can't exponentiate
+        assert!(shares_for_cost_closed_form(0, 25 * b, b, PRECISION).is_err());
+
+        // Cost above MAX_EXP * b: falls back to the search
+        let cost = 25 * b;
+        assert!(shares_for_cost_closed_form(0, 0, b, cost).is_err());
+        let shares = shares_for_cost(0, 0, b, true, cost).unwrap();
+        assert_eq!(shares, shares_for_cost_search(0, 0, b, true, cost).unwrap());
+    }
+
+    #[test]
+    fn test_closed_form_iteration_savings() {
+        let b = 1000 * PRECISION;
+        let cost = 50 * PRECISION;
+
+        let closed = shares_for_cost(b, 0, b, true, cost).unwrap();
+        let search = shares_for_cost_search(b, 0, b, true, cost).unwrap();
+        assert!(closed.abs_diff(search) <= PRECISION / 1000 + 1);
+
+        // Counted in the real code paths: the closed form takes two exponentials
+        // and one logarithm, the search a cost evaluation per bisection step
+        let closed_evaluations = evaluations(|| shares_for_cost(b, 0, b, true, cost));
+        let search_evaluations = evaluations(|| shares_for_cost_search(b, 0, b, true, cost));
+        assert_eq!(closed_evaluations, 3);
+        assert!(
+            closed_evaluations * 10 < search_evaluations,
+            "closed form {} exp/ln evaluations vs search {}", closed_evaluations, search_evaluations
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // Differential tests: exp/ln against f64 reference values
+    // ------------------------------------------------------------------
+
+    /// Deterministic sample points: a uniform grid plus pseudo-random points
+    fn sample_points(max: u64, grid: u64, random: usize) -> Vec<u64> {
+        let mut points: Vec<u64> = (0..=grid).map(|i| max / grid * i).collect();
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for _ in 0..random {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            points.push(state % (max + 1));
+        }
+        points
+    }
+
+    /// e^x for fixed-point x, as exp(integer part) * exp(fraction) so the
+    /// f64 reference does not lose digits converting x itself
+    fn reference_exp(x: u64) -> f64 {
+        let whole = (x / PRECISION) as f64;
+        let frac = (x % PRECISION) as f64 / PRECISION as f64;
+        whole.exp() * frac.exp()
+    }
+
+    #[test]
+    fn test_fixed_exp_matches_reference() {
+        for x in sample_points(MAX_EXP, 20_000, 20_000) {
+            let result = fixed_exp(x).unwrap() as f64;
+            let expected = reference_exp(x) * PRECISION as f64;
+            let bound = 1.0 + 1e-15 * expected;
+            assert!(
+                (result - expected).abs() <= bound,
+                "exp({}) = {}, expected {}", to_f64(x), result, expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_exp_negative_matches_reference() {
+        for x in sample_points(MAX_EXP, 20_000, 20_000) {
+            let result = fixed_exp_negative(x).unwrap() as f64;
+            let expected = PRECISION as f64 / reference_exp(x);
+            assert!(
+                (result - expected).abs() <= 1.0,
+                "exp(-{}) = {}, expected {}", to_f64(x), result, expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_ln_matches_reference() {
+        // ln over [1, 2] (the log-sum-exp range) and across the full u64 domain
+        let mut points: Vec<u64> = sample_points(PRECISION, 10_000, 10_000)
+            .into_iter()
+            .map(|p| PRECISION + p)
+            .collect();
+        points.extend(sample_points(u64::MAX - PRECISION, 10_000, 10_000).into_iter().map(|p| PRECISION + p));
+
+        for x in points {
+            let result = fixed_ln(x).unwrap() as f64;
+            let expected = (x as f64 / PRECISION as f64).ln() * PRECISION as f64;
+            assert!(
+                (result - expected).abs() <= 1.0,
+                "ln({}) = {}, expected {}", to_f64(x), result, expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_exp_ln_monotonic() {
+        let points = sample_points(MAX_EXP, 50_000, 0);
+        for pair in points.windows(2) {
+            assert!(fixed_exp(pair[0]).unwrap() <= fixed_exp(pair[1]).unwrap());
+            assert!(fixed_exp_negative(pair[0]).unwrap() >= fixed_exp_negative(pair[1]).unwrap());
+        }
+        let points = sample_points(100 * PRECISION, 50_000, 0);
+        for pair in points.windows(2) {
+            let (a, b) = (PRECISION + pair[0], PRECISION + pair[1]);
+            assert!(fixed_ln(a).unwrap() <= fixed_ln(b).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_exp_ln_round_trip() {
+        for x in sample_points(MAX_EXP, 2_000, 0) {
+            let round_trip = fixed_ln(fixed_exp(x).unwrap()).unwrap();
+            assert!(round_trip.abs_diff(x) <= 1, "ln(exp({})) = {}", x, round_trip);
+        }
+    }
+
+    #[test]
+    fn test_fixed_ln_rejects_below_one() {
+        assert!(fixed_ln(0).is_err());
+        assert!(fixed_ln(PRECISION - 1).is_err());
+    }
+
     #[test]
     fn test_cost_function_zero_shares() {
         // C(0, 0) = b * ln(2)
@@ -676,15 +950,88 @@ mod tests {
     }
 
     #[test]
-    fn test_log_sum_exp_stability() {
-        // Test that log_sum_exp doesn't overflow with large values
-        let large_x = 15 * PRECISION;
-        let large_y = 14 * PRECISION;
+    fn test_log_sum_exp_correction_term() {
+        // ln(1 + e^0) = ln(2), ln(1 + e^-1) ≈ 0.313262
+        assert!(ln_one_plus_exp_negative(0).unwrap().abs_diff(LN_2) <= 1);
+        assert!(ln_one_plus_exp_negative(PRECISION).unwrap().abs_diff(313_261_687) <= 1);
+
+        // Vanishes for large differences instead of failing
+        assert_eq!(ln_one_plus_exp_negative(25 * PRECISION).unwrap(), 0);
+        assert_eq!(ln_one_plus_exp_negative(u64::MAX).unwrap(), 0);
+        assert_eq!(fixed_exp_negative(EXP_NEGATIVE_CUTOFF).unwrap(), 0);
+    }
+
+    // ------------------------------------------------------------------
+    // Large share volumes: everything depends only on q_yes − q_no
+    // ------------------------------------------------------------------
+
+    #[test]
+    fn test_cost_function_shift_invariant() {
+        // C(q_yes + s, q_no + s) = C(q_yes, q_no) + s, far beyond q/b = 20
+        let b = 100 * PRECISION;
+        let base = cost_function(3 * b, b, b).unwrap();
+        for shift in [50 * b, 1_000 * b, 1_000_000 * b] {
+            assert_eq!(cost_function(3 * b + shift, b + shift, b).unwrap(), base + shift);
+        }
+    }
 
-        let result = log_sum_exp(large_x, large_y).unwrap();
+    #[test]
+    fn test_prices_saturate_for_lopsided_markets() {
+        let b = 100 * PRECISION;
+
+        // Difference of 25b: e^-25 is below an ulp
+        assert_eq!(calculate_yes_price(25 * b, 0, b).unwrap(), PRECISION);
+        assert_eq!(calculate_no_price(25 * b, 0, b).unwrap(), 0);
+        assert_eq!(calculate_yes_price(0, 25 * b, b).unwrap(), 0);
+
+        // Difference of 21b: still resolves to about 0.76 ulp
+        let yes_price = calculate_yes_price(0, 21 * b, b).unwrap();
+        assert_eq!(yes_price, 1);
+        assert_eq!(yes_price + calculate_no_price(0, 21 * b, b).unwrap(), PRECISION);
+
+        // Prices only depend on the difference
+        let shift = 10_000 * b;
+        assert_eq!(
+            calculate_yes_price(2 * b, b, b).unwrap(),
+            calculate_yes_price(2 * b + shift, b + shift, b).unwrap()
+        );
+    }
 
-        // Result should be close to max(x,y) when values are large
-        assert!(result > large_x);
-        assert!(result < large_x + 2 * PRECISION); // Within reasonable range
+    #[test]
+    fn test_popular_market_stays_tradable() {
+        // YES leads by 40b; both sides must still be buyable and sellable
+        let b = 100 * PRECISION;
+        let (q_yes, q_no) = (40 * b, 0);
+        let cost = 10 * PRECISION;
+
+        // Favored side: costs about one lamport per share
+        let (actual, shares) = calculate_buy_cost(q_yes, q_no, b, true, cost).unwrap();
+        assert!(actual.abs_diff(cost) <= 1_000);
+        assert!(shares.abs_diff(cost) <= 1_000);
+
+        // Long shot: the closed form falls back to the search
+        let (actual, shares) = calculate_buy_cost(q_yes, q_no, b, false, cost).unwrap();
+        assert!(actual <= cost && cost - actual <= PRECISION / 1000);
+        assert!(shares > 30 * b);
+
+        // Selling the favored side back returns its price
+        let proceeds = calculate_sell_proceeds(q_yes, q_no, b, true, cost).unwrap();
+        assert_eq!(proceeds, cost);
+    }
+
+    #[test]
+    fn test_buy_beyond_twenty_b() {
+        // A single purchase can now move the market more than 20b
+        let b = 100 * PRECISION;
+        let cost = 30 * b;
+        let shares = shares_for_cost(0, 0, b, true, cost).unwrap();
+        assert!(shares > 20 * b);
+
+        let (actual, _) = calculate_buy_cost(0, 0, b, true, cost).unwrap();
+        assert!(actual <= cost && cost - actual <= PRECISION / 1000);
+
+        // Same trade at a large absolute volume buys the same shares
+        let shift = 5_000 * b;
+        assert_eq!(shares_for_cost(shift, shift, b, true, cost).unwrap(), shares);
     }
 }
@@ -0,0 +1,378 @@
+// LS-LMSR (Liquidity-Sensitive LMSR, Othman et al.)
+//
+// The LMSR with its liquidity parameter scaled by the shares outstanding:
+//   b(q) = α * (q_yes + q_no)
+//   C(q) = b(q) * ln(e^(q_yes/b(q)) + e^(q_no/b(q)))
+//
+// Thin markets start shallow and deepen as volume grows, instead of using
+// one fixed b for their whole life. Prices sum to slightly more than 1
+// (the market maker's built-in spread), and the worst-case loss is the cost
+// of the initial share state, C(q0).
+//
+// API mirrors math::lmsr, with α in place of b.
+
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use super::fixed_point::{fixed_div, fixed_mul};
+use super::lmsr::{favored_side_price, ln_one_plus_exp_negative, log_sum_exp_cost};
+use super::{LN_2, PRECISION};
+
+/// Minimum liquidity sensitivity α (0.001)
+pub const MIN_ALPHA: u64 = PRECISION / 1000;
+
+/// Maximum liquidity sensitivity α (0.1)
+///
+/// At α = 0.1 prices sum to at most 1 + 2α ln(2) ≈ 1.139 in a balanced market.
+pub const MAX_ALPHA: u64 = PRECISION / 10;
+
+/// Minimum shares per outcome at creation (1 share)
+///
+/// b(q) is proportional to the share total, so the market needs a non-zero
+/// starting inventory to be priceable at all.
+pub const MIN_INITIAL_SHARES: u64 = PRECISION;
+
+// ============================================================================
+// Core LS-LMSR Functions
+// ============================================================================
+
+/// Liquidity parameter for the current share totals: b = α * (q_yes + q_no)
+pub fn liquidity(q_yes: u64, q_no: u64, alpha: u64) -> Result<u64> {
+    let total = q_yes.checked_add(q_no).ok_or(ErrorCode::OverflowError)?;
+    fixed_mul(alpha, total)
+}
+
+/// Calculate LS-LMSR cost function
+///
+/// Formula: C(q) = b(q) * ln(e^(q_yes/b(q)) + e^(q_no/b(q)))
+///
+/// As b → 0 the cost tends to max(q_yes, q_no), which is returned when b
+/// rounds to zero.
+///
+/// # Arguments
+/// * `q_yes` - Outstanding YES shares (fixed-point, 9 decimals)
+/// * `q_no` - Outstanding NO shares (fixed-point, 9 decimals)
+/// * `alpha` - Liquidity sensitivity (fixed-point, MIN_ALPHA..=MAX_ALPHA)
+///
+/// # Returns
+/// * `Result<u64>` - Total cost to create current market state (lamports)
+pub fn cost_function(q_yes: u64, q_no: u64, alpha: u64) -> Result<u64> {
+    let b = liquidity(q_yes, q_no, alpha)?;
+    if b == 0 {
+        return Ok(q_yes.max(q_no));
+    }
+    log_sum_exp_cost(q_yes, q_no, b)
+}
+
+/// Price premium shared by both outcomes
+///
+/// Differentiating C(q) with b = α * S (S = q_yes + q_no) gives, for either
+/// outcome, its softmax price plus
+///   α * ln(1 + e^(-|d|/b)) + π_behind * |d| / S
+/// where d = q_yes − q_no and π_behind is the softmax price of the trailing
+/// side. The premium is α ln(2) in a balanced market and shrinks toward 0
+/// as the market becomes one-sided.
+fn price_premium(diff: u64, total: u64, b: u64, alpha: u64, behind_price: u64) -> Result<u64> {
+    let spread = fixed_mul(alpha, ln_one_plus_exp_negative(fixed_div(diff, b)?)?)?;
+    let skew = fixed_mul(behind_price, fixed_div(diff, total)?)?;
+    spread.checked_add(skew).ok_or(ErrorCode::OverflowError.into())
+}
+
+/// Calculate YES outcome price (marginal cost of one YES share)
+///
+/// Formula: P(YES) = softmax(q_yes) + premium (see `price_premium`)
+///
+/// Unlike the LMSR, P(YES) + P(NO) ≥ 1.
+///
+/// # Returns
+/// * `Result<u64>` - YES price in fixed-point
+pub fn calculate_yes_price(q_yes: u64, q_no: u64, alpha: u64) -> Result<u64> {
+    outcome_price(q_yes, q_no, alpha, true)
+}
+
+/// Calculate NO outcome price (marginal cost of one NO share)
+///
+/// # Returns
+/// * `Result<u64>` - NO price in fixed-point
+pub fn calculate_no_price(q_yes: u64, q_no: u64, alpha: u64) -> Result<u64> {
+    outcome_price(q_yes, q_no, alpha, false)
+}
+
+fn outcome_price(q_yes: u64, q_no: u64, alpha: u64, outcome: bool) -> Result<u64> {
+    let total = q_yes.checked_add(q_no).ok_or(ErrorCode::OverflowError)?;
+    let b = liquidity(q_yes, q_no, alpha)?;
+    require!(b > 0, ErrorCode::DivisionByZero);
+
+    let diff = q_yes.abs_diff(q_no);
+    let ahead_price = favored_side_price(diff, b)?;
+    let behind_price = PRECISION - ahead_price;
+    let premium = price_premium(diff, total, b, alpha, behind_price)?;
+
+    let (q_self, q_other) = if outcome { (q_yes, q_no) } else { (q_no, q_yes) };
+    let softmax_price = if q_self >= q_other { ahead_price } else { behind_price };
+
+    softmax_price.checked_add(premium).ok_or(ErrorCode::OverflowError.into())
+}
+
+// ============================================================================
+// Trading Operations
+// ============================================================================
+
+/// Binary search for the number of shares that costs `target_cost`
+///
+/// b changes with every share bought, so there is no closed-form inverse as
+/// for the LMSR.
+///
+/// # Returns
+/// * `Result<u64>` - Number of shares that matches target cost
+pub fn shares_for_cost(
+    current_q_yes: u64,
+    current_q_no: u64,
+    alpha: u64,
+    outcome: bool,
+    target_cost: u64,
+) -> Result<u64> {
+    let cost_before = cost_function(current_q_yes, current_q_no, alpha)?;
+    let q_self = if outcome { current_q_yes } else { current_q_no };
+
+    let mut low: u64 = 0;
+    // Upper bound: C(q) ≥ max(q), so after buying Δ shares
+    // C ≥ q_self + Δ, which exceeds cost_before + target once
+    // Δ > target + cost_before − q_self
+    let mut high: u64 = target_cost
+        .saturating_add(cost_before.saturating_sub(q_self))
+        .saturating_add(1)
+        .min(u64::MAX - q_self);
+    let tolerance: u64 = PRECISION / 1000; // 0.001 tolerance
+
+    // Binary search with max 50 iterations
+    for _ in 0..50 {
+        if high - low <= tolerance {
+            break;
+        }
+
+        let mid = low + (high - low) / 2;
+        let (new_q_yes, new_q_no) = if outcome {
+            (current_q_yes + mid, current_q_no)
+        } else {
+            (current_q_yes, current_q_no + mid)
+        };
+
+        let cost_after = cost_function(new_q_yes, new_q_no, alpha)?;
+        let actual_cost = cost_after.checked_sub(cost_before).ok_or(ErrorCode::UnderflowError)?;
+
+        if actual_cost < target_cost {
+            low = mid + 1;
+        } else if actual_cost > target_cost {
+            high = mid;
+        } else {
+            return Ok(mid);
+        }
+    }
+
+    Ok(low)
+}
+
+/// Calculate cost to buy shares and return (cost, shares) tuple
+///
+/// # Returns
+/// * `Result<(u64, u64)>` - Tuple of (actual_cost, shares_bought)
+pub fn calculate_buy_cost(
+    current_q_yes: u64,
+    current_q_no: u64,
+    alpha: u64,
+    outcome: bool,
+    target_cost: u64,
+) -> Result<(u64, u64)> {
+    let shares = shares_for_cost(current_q_yes, current_q_no, alpha, outcome, target_cost)?;
+
+    let (new_q_yes, new_q_no) = if outcome {
+        (current_q_yes.checked_add(shares).ok_or(ErrorCode::OverflowError)?, current_q_no)
+    } else {
+        (current_q_yes, current_q_no.checked_add(shares).ok_or(ErrorCode::OverflowError)?)
+    };
+
+    let cost_before = cost_function(current_q_yes, current_q_no, alpha)?;
+    let cost_after = cost_function(new_q_yes, new_q_no, alpha)?;
+    let actual_cost = cost_after.checked_sub(cost_before).ok_or(ErrorCode::UnderflowError)?;
+
+    Ok((actual_cost, shares))
+}
+
+/// Calculate proceeds from selling shares
+///
+/// Formula: Proceeds = C(q) - C(q - Δq)
+///
+/// # Returns
+/// * `Result<u64>` - Proceeds from sale (before fees are deducted)
+pub fn calculate_sell_proceeds(
+    current_q_yes: u64,
+    current_q_no: u64,
+    alpha: u64,
+    outcome: bool,
+    shares_to_sell: u64,
+) -> Result<u64> {
+    let (new_q_yes, new_q_no) = if outcome {
+        (
+            current_q_yes.checked_sub(shares_to_sell).ok_or(ErrorCode::InsufficientShares)?,
+            current_q_no
+        )
+    } else {
+        (
+            current_q_yes,
+            current_q_no.checked_sub(shares_to_sell).ok_or(ErrorCode::InsufficientShares)?
+        )
+    };
+
+    let cost_before = cost_function(current_q_yes, current_q_no, alpha)?;
+    let cost_after = cost_function(new_q_yes, new_q_no, alpha)?;
+    let proceeds = cost_before.checked_sub(cost_after).ok_or(ErrorCode::UnderflowError)?;
+
+    Ok(proceeds)
+}
+
+// ============================================================================
+// Market Setup and Bounded Loss
+// ============================================================================
+
+/// Shares per outcome that give a balanced market a starting depth of `b`
+///
+/// b(q0, q0) = 2α * q0, so q0 = b / (2α).
+pub fn initial_shares(b: u64, alpha: u64) -> Result<u64> {
+    let two_alpha = alpha.checked_mul(2).ok_or(ErrorCode::OverflowError)?;
+    fixed_div(b, two_alpha)
+}
+
+/// Maximum loss for an LS-LMSR market that started at depth `b`
+///
+/// Formula: Max Loss = C(q0, q0) = q0 + b * ln(2), with q0 = b / (2α)
+///
+/// Winning shares never pay out more than max(q) ≤ C(q), so the market
+/// maker can lose at most what the initial share state cost.
+pub fn calculate_max_loss(b: u64, alpha: u64) -> Result<u64> {
+    let q0 = initial_shares(b, alpha)?;
+    cost_function(q0, q0, alpha)
+}
+
+/// Starting depth b whose worst-case loss `subsidy` covers
+///
+/// Inverts Max Loss = q0 * (1 + 2α ln(2)) for q0 and returns b = 2α * q0.
+/// ln(2) is taken one ulp high (fixed_ln may round up to it) and q0 is
+/// rounded down, so the loss bound never exceeds the subsidy.
+///
+/// # Errors
+/// Returns `InvalidLiquidity` if the subsidy buys fewer than
+/// MIN_INITIAL_SHARES per outcome
+pub fn b_from_subsidy(subsidy: u64, alpha: u64) -> Result<u64> {
+    let two_alpha = alpha.checked_mul(2).ok_or(ErrorCode::OverflowError)?;
+    let loss_per_share = PRECISION
+        .checked_add(fixed_mul(two_alpha, LN_2 + 1)?)
+        .ok_or(ErrorCode::OverflowError)?
+        .checked_add(1)
+        .ok_or(ErrorCode::OverflowError)?;
+    let q0 = fixed_div(subsidy, loss_per_share)?;
+    require!(q0 >= MIN_INITIAL_SHARES, ErrorCode::InvalidLiquidity);
+
+    fixed_mul(two_alpha, q0)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::lmsr;
+
+    const ALPHA: u64 = PRECISION / 20; // 0.05
+
+    #[test]
+    fn test_cost_matches_lmsr_at_same_depth() {
+        // At a given state, LS-LMSR cost equals LMSR cost with b = α * Σq
+        let (q_yes, q_no) = (3_000 * PRECISION, 1_000 * PRECISION);
+        let b = liquidity(q_yes, q_no, ALPHA).unwrap();
+        assert_eq!(b, 200 * PRECISION);
+        assert_eq!(
+            cost_function(q_yes, q_no, ALPHA).unwrap(),
+            lmsr::cost_function(q_yes, q_no, b).unwrap()
+        );
+        assert_eq!(cost_function(0, 0, ALPHA).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_depth_grows_with_volume() {
+        // The same purchase moves a thin market more than a deep one
+        let cost = 10 * PRECISION;
+        let thin = 100 * PRECISION;
+        let deep = 100_000 * PRECISION;
+
+        let move_in = |q: u64| {
+            let (_, shares) = calculate_buy_cost(q, q, ALPHA, true, cost).unwrap();
+            calculate_yes_price(q + shares, q, ALPHA).unwrap() - calculate_yes_price(q, q, ALPHA).unwrap()
+        };
+        assert!(move_in(thin) > 10 * move_in(deep));
+    }
+
+    #[test]
+    fn test_prices_include_spread() {
+        // Balanced market: each price is 1/2 + α ln(2)
+        let q = 1_000 * PRECISION;
+        let expected = PRECISION / 2 + fixed_mul(ALPHA, LN_2).unwrap();
+        let yes = calculate_yes_price(q, q, ALPHA).unwrap();
+        let no = calculate_no_price(q, q, ALPHA).unwrap();
+        assert_eq!(yes, no);
+        assert!(yes.abs_diff(expected) <= 2, "yes {} expected {}", yes, expected);
+
+        // Lopsided market: favored side above its LMSR price, sum still ≥ 1
+        let (q_yes, q_no) = (5_000 * PRECISION, 1_000 * PRECISION);
+        let yes = calculate_yes_price(q_yes, q_no, ALPHA).unwrap();
+        let no = calculate_no_price(q_yes, q_no, ALPHA).unwrap();
+        assert!(yes > no);
+        assert!(yes + no >= PRECISION);
+    }
+
+    #[test]
+    fn test_price_matches_marginal_cost() {
+        // Price is the derivative of the cost function
+        let (q_yes, q_no) = (4_000 * PRECISION, 2_500 * PRECISION);
+        let step = PRECISION;
+        for outcome in [true, false] {
+            let (new_yes, new_no) = if outcome { (q_yes + step, q_no) } else { (q_yes, q_no + step) };
+            let marginal = cost_function(new_yes, new_no, ALPHA).unwrap()
+                - cost_function(q_yes, q_no, ALPHA).unwrap();
+            let price = if outcome {
+                calculate_yes_price(q_yes, q_no, ALPHA).unwrap()
+            } else {
+                calculate_no_price(q_yes, q_no, ALPHA).unwrap()
+            };
+            assert!(marginal.abs_diff(price) <= PRECISION / 10_000, "{} vs {}", marginal, price);
+        }
+    }
+
+    #[test]
+    fn test_buy_sell_round_trip() {
+        let q = 1_000 * PRECISION;
+        let cost = 50 * PRECISION;
+        let (actual, shares) = calculate_buy_cost(q, q, ALPHA, false, cost).unwrap();
+        assert!(actual.abs_diff(cost) <= PRECISION / 1000);
+
+        // Selling straight back returns what was paid
+        let proceeds = calculate_sell_proceeds(q, q + shares, ALPHA, false, shares).unwrap();
+        assert_eq!(proceeds, actual);
+    }
+
+    #[test]
+    fn test_subsidy_covers_max_loss() {
+        for alpha in [MIN_ALPHA, ALPHA, MAX_ALPHA] {
+            for subsidy in [10 * PRECISION, 693 * PRECISION + 123, 1_000_000 * PRECISION] {
+                let b = b_from_subsidy(subsidy, alpha).unwrap();
+                let max_loss = calculate_max_loss(b, alpha).unwrap();
+                assert!(max_loss <= subsidy, "alpha {} subsidy {}: loss {}", alpha, subsidy, max_loss);
+                assert!(subsidy - max_loss <= subsidy / 1_000_000 + 10);
+            }
+        }
+
+        // Too small to seed one share per outcome
+        assert!(b_from_subsidy(PRECISION / 2, ALPHA).is_err());
+    }
+}
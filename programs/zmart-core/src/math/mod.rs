@@ -2,11 +2,15 @@
 
 pub mod fixed_point;
 pub mod lmsr;
+pub mod ls_lmsr;
 pub mod bounded_loss;
+pub mod pricing_curve;
 
 pub use fixed_point::*;
 pub use lmsr::*;
 pub use bounded_loss::*;
+pub use pricing_curve::*;
+// ls_lmsr is used by path: its API mirrors lmsr's names
 
 /// Fixed-point precision: 9 decimals (matches SOL lamports)
 ///
@@ -0,0 +1,176 @@
+// Pricing curve abstraction
+//
+// Instructions price trades through `PricingCurve` rather than calling a
+// specific market maker module, so new market types only need a new
+// implementation here and a `CurveType` variant.
+
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use super::{bounded_loss, fixed_div, lmsr, ls_lmsr, PRECISION};
+
+/// Market maker pricing curve over a binary (YES/NO) share state
+///
+/// Share quantities and prices are fixed-point (9 decimals), costs are in
+/// lamports. `outcome` is true for YES and false for NO.
+pub trait PricingCurve {
+    /// Total cost C(q_yes, q_no) of the current share state
+    fn cost(&self, q_yes: u64, q_no: u64) -> Result<u64>;
+
+    /// Marginal price of one share of `outcome`
+    fn price(&self, q_yes: u64, q_no: u64, outcome: bool) -> Result<u64>;
+
+    /// Shares of `outcome` that `target_cost` buys, as (actual_cost, shares)
+    fn buy_cost(&self, q_yes: u64, q_no: u64, outcome: bool, target_cost: u64) -> Result<(u64, u64)>;
+
+    /// Proceeds (before fees) from selling `shares` of `outcome`
+    fn sell_proceeds(&self, q_yes: u64, q_no: u64, outcome: bool, shares: u64) -> Result<u64>;
+
+    /// Worst-case loss of the market maker's subsidy (lamports)
+    fn max_loss(&self) -> Result<u64>;
+}
+
+/// LMSR with a fixed liquidity parameter (see `math::lmsr`)
+pub struct LmsrCurve {
+    pub b: u64,
+}
+
+impl PricingCurve for LmsrCurve {
+    fn cost(&self, q_yes: u64, q_no: u64) -> Result<u64> {
+        lmsr::cost_function(q_yes, q_no, self.b)
+    }
+
+    fn price(&self, q_yes: u64, q_no: u64, outcome: bool) -> Result<u64> {
+        if outcome {
+            lmsr::calculate_yes_price(q_yes, q_no, self.b)
+        } else {
+            lmsr::calculate_no_price(q_yes, q_no, self.b)
+        }
+    }
+
+    fn buy_cost(&self, q_yes: u64, q_no: u64, outcome: bool, target_cost: u64) -> Result<(u64, u64)> {
+        lmsr::calculate_buy_cost(q_yes, q_no, self.b, outcome, target_cost)
+    }
+
+    fn sell_proceeds(&self, q_yes: u64, q_no: u64, outcome: bool, shares: u64) -> Result<u64> {
+        lmsr::calculate_sell_proceeds(q_yes, q_no, self.b, outcome, shares)
+    }
+
+    fn max_loss(&self) -> Result<u64> {
+        bounded_loss::calculate_max_loss(self.b)
+    }
+}
+
+/// Liquidity-sensitive LMSR, b = alpha * (q_yes + q_no) (see `math::ls_lmsr`)
+pub struct LsLmsrCurve {
+    pub alpha: u64,
+    /// Depth at creation, which fixes the initial share state and loss bound
+    pub initial_b: u64,
+}
+
+impl PricingCurve for LsLmsrCurve {
+    fn cost(&self, q_yes: u64, q_no: u64) -> Result<u64> {
+        ls_lmsr::cost_function(q_yes, q_no, self.alpha)
+    }
+
+    fn price(&self, q_yes: u64, q_no: u64, outcome: bool) -> Result<u64> {
+        if outcome {
+            ls_lmsr::calculate_yes_price(q_yes, q_no, self.alpha)
+        } else {
+            ls_lmsr::calculate_no_price(q_yes, q_no, self.alpha)
+        }
+    }
+
+    fn buy_cost(&self, q_yes: u64, q_no: u64, outcome: bool, target_cost: u64) -> Result<(u64, u64)> {
+        ls_lmsr::calculate_buy_cost(q_yes, q_no, self.alpha, outcome, target_cost)
+    }
+
+    fn sell_proceeds(&self, q_yes: u64, q_no: u64, outcome: bool, shares: u64) -> Result<u64> {
+        ls_lmsr::calculate_sell_proceeds(q_yes, q_no, self.alpha, outcome, shares)
+    }
+
+    fn max_loss(&self) -> Result<u64> {
+        ls_lmsr::calculate_max_loss(self.initial_b, self.alpha)
+    }
+}
+
+/// Parimutuel pool: stakes buy shares 1:1 and winners split the pool
+///
+/// There is no market maker, so no price discovery and no subsidy at risk.
+/// Payouts use claim_winnings' pro-rata formula, which for 1:1 shares is the
+/// parimutuel dividend (stake / winning pool × total pool). Stakes are final
+/// once placed.
+pub struct ParimutuelCurve;
+
+impl PricingCurve for ParimutuelCurve {
+    /// Total staked
+    fn cost(&self, q_yes: u64, q_no: u64) -> Result<u64> {
+        q_yes.checked_add(q_no).ok_or(ErrorCode::OverflowError.into())
+    }
+
+    /// Implied probability: the outcome's share of the pool (0.5 when empty)
+    fn price(&self, q_yes: u64, q_no: u64, outcome: bool) -> Result<u64> {
+        let total = self.cost(q_yes, q_no)?;
+        if total == 0 {
+            return Ok(PRECISION / 2);
+        }
+        fixed_div(if outcome { q_yes } else { q_no }, total)
+    }
+
+    fn buy_cost(&self, _q_yes: u64, _q_no: u64, _outcome: bool, target_cost: u64) -> Result<(u64, u64)> {
+        Ok((target_cost, target_cost))
+    }
+
+    fn sell_proceeds(&self, _q_yes: u64, _q_no: u64, _outcome: bool, _shares: u64) -> Result<u64> {
+        err!(ErrorCode::SellNotSupported)
+    }
+
+    fn max_loss(&self) -> Result<u64> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buy_then_sell_is_cost_difference() {
+        // Holds for any curve: buying then selling the same shares nets to zero
+        let curves: [Box<dyn PricingCurve>; 2] = [
+            Box::new(LmsrCurve { b: 1_000 * PRECISION }),
+            Box::new(LsLmsrCurve { alpha: PRECISION / 20, initial_b: 100 * PRECISION }),
+        ];
+        let (q_yes, q_no) = (1_000 * PRECISION, 1_000 * PRECISION);
+
+        for curve in &curves {
+            let (cost, shares) = curve.buy_cost(q_yes, q_no, true, 25 * PRECISION).unwrap();
+            assert_eq!(
+                cost,
+                curve.cost(q_yes + shares, q_no).unwrap() - curve.cost(q_yes, q_no).unwrap()
+            );
+            assert_eq!(curve.sell_proceeds(q_yes + shares, q_no, true, shares).unwrap(), cost);
+            assert!(curve.price(q_yes + shares, q_no, true).unwrap() > curve.price(q_yes, q_no, true).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parimutuel_curve() {
+        let curve = ParimutuelCurve;
+
+        // Stakes are 1:1 shares and prices are pool shares
+        assert_eq!(curve.buy_cost(0, 0, true, 5 * PRECISION).unwrap(), (5 * PRECISION, 5 * PRECISION));
+        assert_eq!(curve.price(0, 0, true).unwrap(), PRECISION / 2);
+        assert_eq!(curve.price(3 * PRECISION, PRECISION, true).unwrap(), 750_000_000);
+        assert_eq!(curve.price(3 * PRECISION, PRECISION, false).unwrap(), 250_000_000);
+
+        // No market maker: nothing at risk, nothing to sell back to
+        assert_eq!(curve.max_loss().unwrap(), 0);
+        assert!(curve.sell_proceeds(PRECISION, 0, true, PRECISION).is_err());
+    }
+
+    #[test]
+    fn test_lmsr_curve_max_loss() {
+        let curve = LmsrCurve { b: 1_000 * PRECISION };
+        assert_eq!(curve.max_loss().unwrap(), 693_147_180_000);
+    }
+}
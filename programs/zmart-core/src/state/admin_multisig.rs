@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Maximum number of signers in the admin multisig
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// M-of-N signer set that can act as protocol admin
+///
+/// Once installed via initialize_admin_multisig, `GlobalConfig::admin` is this
+/// PDA's address. Admin instructions are then only reachable through
+/// execute_admin_action, which signs for the PDA after `threshold` signers
+/// have approved a PendingAdminAction.
+///
+/// PDA Seeds: ["admin-multisig"]
+#[account]
+pub struct AdminMultisig {
+    /// Signer set (only the first `signer_count` entries are used)
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+
+    /// Number of active signers (1..=MAX_MULTISIG_SIGNERS)
+    pub signer_count: u8,
+
+    /// Approvals required to execute an action (1..=signer_count)
+    pub threshold: u8,
+
+    /// Incremented on every proposed action, used in PendingAdminAction seeds
+    pub action_nonce: u64,
+
+    /// Incremented whenever the signer set changes; approvals collected under
+    /// an older set can no longer execute
+    pub signer_set_seq: u32,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    /// Calculate exact account size
+    pub const LEN: usize = 8                       // discriminator
+        + 32 * MAX_MULTISIG_SIGNERS                // signers
+        + 1                                        // signer_count
+        + 1                                        // threshold
+        + 8                                        // action_nonce
+        + 4                                        // signer_set_seq
+        + 1;                                       // bump
+
+    /// Replace the signer set after validating it
+    ///
+    /// # Errors
+    /// Returns `InvalidMultisigConfig` if the set is empty, too large, has
+    /// duplicates, or the threshold is outside 1..=signers.len()
+    pub fn set_signers(&mut self, signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            ErrorCode::InvalidMultisigConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            ErrorCode::InvalidMultisigConfig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                *signer != Pubkey::default() && !signers[..i].contains(signer),
+                ErrorCode::InvalidMultisigConfig
+            );
+        }
+
+        self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        self.signer_count = signers.len() as u8;
+        self.threshold = threshold;
+        self.signer_set_seq = self.signer_set_seq.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Position of `key` in the active signer set
+    ///
+    /// # Errors
+    /// Returns `NotMultisigSigner` if `key` is not an active signer
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers[..self.signer_count as usize]
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or_else(|| error!(ErrorCode::NotMultisigSigner))
+    }
+}
+
+/// Admin operation awaiting multisig approval
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    /// approve_proposal on `market`
+    ApproveProposal { market: Pubkey },
+    /// cancel_market on `market`
+    CancelMarket { market: Pubkey },
+    /// emergency_pause with the given PAUSE_* flags
+    EmergencyPause { paused_operations: u8 },
+    /// update_global_config with the given arguments
+    UpdateGlobalConfig {
+        protocol_fee_bps: u16,
+        resolver_reward_bps: u16,
+        liquidity_provider_fee_bps: u16,
+        proposal_approval_threshold: u16,
+        dispute_success_threshold: u16,
+        min_resolution_delay: Option<i64>,
+        dispute_period: Option<i64>,
+        claim_period: Option<i64>,
+    },
+    /// propose_admin, e.g. to hand admin back to a single key
+    ProposeAdmin { new_admin: Pubkey },
+    /// Replace the multisig signer set and threshold
+    UpdateSigners { signers: Vec<Pubkey>, threshold: u8 },
+    /// cancel_config_update (drop the queued config change)
+    CancelConfigUpdate,
+    /// freeze_market on `market`
+    FreezeMarket { market: Pubkey, frozen: bool },
+    /// update_guardian
+    UpdateGuardian { new_guardian: Pubkey },
+    /// update_backend_authority
+    UpdateBackendAuthority { new_backend_authority: Pubkey },
+    /// update_protocol_fee_wallet to `protocol_fee_wallet`
+    UpdateProtocolFeeWallet { protocol_fee_wallet: Pubkey },
+    /// close_market on `market`
+    CloseMarket { market: Pubkey },
+    /// sweep_unclaimed on `market`
+    SweepUnclaimed { market: Pubkey },
+}
+
+impl AdminAction {
+    /// Largest serialized size of any variant (UpdateSigners with a full set)
+    pub const MAX_LEN: usize = 1 + 4 + 32 * MAX_MULTISIG_SIGNERS + 1;
+}
+
+/// Admin action proposed by a multisig signer, collecting approvals
+///
+/// PDA Seeds: ["admin-action", multisig.key(), action_nonce (u64 LE)]
+#[account]
+pub struct PendingAdminAction {
+    /// Multisig this action belongs to
+    pub multisig: Pubkey,
+
+    /// Signer who proposed the action (receives rent on execution or cancellation)
+    pub proposer: Pubkey,
+
+    /// Operation to execute once approved
+    pub action: AdminAction,
+
+    /// Bitmap of approving signers, indexed by position in the signer set
+    pub approvals: u16,
+
+    /// `AdminMultisig::signer_set_seq` when proposed
+    pub signer_set_seq: u32,
+
+    /// Proposal timestamp
+    pub created_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PendingAdminAction {
+    /// Calculate maximum account size
+    pub const LEN: usize = 8                       // discriminator
+        + 32                                       // multisig
+        + 32                                       // proposer
+        + AdminAction::MAX_LEN                     // action
+        + 2                                        // approvals
+        + 4                                        // signer_set_seq
+        + 8                                        // created_at
+        + 1;                                       // bump
+
+    /// Record approval from the signer at `index`
+    ///
+    /// # Errors
+    /// Returns `AlreadyApproved` if this signer already approved
+    pub fn approve(&mut self, index: usize) -> Result<()> {
+        let bit = 1u16 << index;
+        require!(self.approvals & bit == 0, ErrorCode::AlreadyApproved);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    /// Number of approvals collected
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// Whether this action can no longer execute
+    ///
+    /// True once the signer set was replaced (including when a handed-back
+    /// multisig is reinitialized) or while the multisig does not hold admin.
+    pub fn is_stale(&self, multisig: &AdminMultisig, multisig_key: &Pubkey, admin: &Pubkey) -> bool {
+        self.signer_set_seq != multisig.signer_set_seq || admin != multisig_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_multisig() -> AdminMultisig {
+        AdminMultisig {
+            signers: [Pubkey::default(); MAX_MULTISIG_SIGNERS],
+            signer_count: 0,
+            threshold: 0,
+            action_nonce: 0,
+            signer_set_seq: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_set_signers_validation() {
+        let mut multisig = create_test_multisig();
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+
+        assert!(multisig.set_signers(&[], 1).is_err());
+        assert!(multisig.set_signers(&[a, b], 0).is_err());
+        assert!(multisig.set_signers(&[a, b], 3).is_err());
+        assert!(multisig.set_signers(&[a, a], 1).is_err());
+        assert!(multisig.set_signers(&[a, Pubkey::default()], 1).is_err());
+        assert!(multisig.set_signers(&[Pubkey::new_unique(); MAX_MULTISIG_SIGNERS + 1], 1).is_err());
+
+        multisig.set_signers(&[a, b, c], 2).unwrap();
+        assert_eq!(multisig.signer_count, 3);
+        assert_eq!(multisig.threshold, 2);
+        assert_eq!(multisig.signer_set_seq, 1);
+        assert_eq!(multisig.signer_index(&c).unwrap(), 2);
+        assert!(multisig.signer_index(&Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn test_approvals() {
+        let mut action = PendingAdminAction {
+            multisig: Pubkey::new_unique(),
+            proposer: Pubkey::new_unique(),
+            action: AdminAction::EmergencyPause { paused_operations: 0 },
+            approvals: 0,
+            signer_set_seq: 1,
+            created_at: 0,
+            bump: 255,
+        };
+
+        action.approve(0).unwrap();
+        action.approve(9).unwrap();
+        assert!(action.approve(0).is_err()); // No double approval
+        assert_eq!(action.approval_count(), 2);
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let mut multisig = create_test_multisig();
+        multisig.set_signers(&[Pubkey::new_unique(), Pubkey::new_unique()], 2).unwrap();
+        let multisig_key = Pubkey::new_unique();
+        let action = PendingAdminAction {
+            multisig: multisig_key,
+            proposer: multisig.signers[0],
+            action: AdminAction::CancelConfigUpdate,
+            approvals: 1,
+            signer_set_seq: multisig.signer_set_seq,
+            created_at: 0,
+            bump: 255,
+        };
+        assert!(!action.is_stale(&multisig, &multisig_key, &multisig_key));
+
+        // Admin handed back to a single key
+        assert!(action.is_stale(&multisig, &multisig_key, &Pubkey::new_unique()));
+
+        // Reinitializing (or UpdateSigners) replaces the signer set for good
+        multisig.set_signers(&[Pubkey::new_unique()], 1).unwrap();
+        assert!(action.is_stale(&multisig, &multisig_key, &multisig_key));
+    }
+
+    #[test]
+    fn test_action_fits_in_account() {
+        let action = AdminAction::UpdateSigners {
+            signers: vec![Pubkey::new_unique(); MAX_MULTISIG_SIGNERS],
+            threshold: 5,
+        };
+        assert_eq!(action.try_to_vec().unwrap().len(), AdminAction::MAX_LEN);
+
+        let config_update = AdminAction::UpdateGlobalConfig {
+            protocol_fee_bps: 300,
+            resolver_reward_bps: 200,
+            liquidity_provider_fee_bps: 500,
+            proposal_approval_threshold: 7000,
+            dispute_success_threshold: 6000,
+            min_resolution_delay: Some(1),
+            dispute_period: Some(1),
+            claim_period: Some(1),
+        };
+        assert!(config_update.try_to_vec().unwrap().len() <= AdminAction::MAX_LEN);
+
+        // Every other variant, so a new one cannot outgrow the account unnoticed
+        let key = Pubkey::new_unique();
+        let others = [
+            AdminAction::ApproveProposal { market: key },
+            AdminAction::CancelMarket { market: key },
+            AdminAction::EmergencyPause { paused_operations: 0xff },
+            AdminAction::ProposeAdmin { new_admin: key },
+            AdminAction::CancelConfigUpdate,
+            AdminAction::FreezeMarket { market: key, frozen: true },
+            AdminAction::UpdateGuardian { new_guardian: key },
+            AdminAction::UpdateBackendAuthority { new_backend_authority: key },
+            AdminAction::UpdateProtocolFeeWallet { protocol_fee_wallet: key },
+            AdminAction::CloseMarket { market: key },
+            AdminAction::SweepUnclaimed { market: key },
+        ];
+        for other in others {
+            assert!(other.try_to_vec().unwrap().len() <= AdminAction::MAX_LEN);
+        }
+    }
+}
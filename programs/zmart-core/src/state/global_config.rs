@@ -1,13 +1,63 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
 
+/// `is_paused = true` as written by configs before the PAUSE_* flags
+///
+/// Never set by `set_paused_operations`; read as the operations the old flag
+/// covered (see `GlobalConfig::paused_flags`).
+pub const LEGACY_PAUSED: u8 = 1 << 0;
+/// Pause flag: create_market
+pub const PAUSE_CREATE: u8 = 1 << 1;
+/// Pause flag: buy_shares
+pub const PAUSE_BUY: u8 = 1 << 2;
+/// Pause flag: sell_shares
+pub const PAUSE_SELL: u8 = 1 << 3;
+/// Pause flag: claim_winnings, claim_many, claim_cancellation_refund, claim_resolver_fees
+pub const PAUSE_CLAIM: u8 = 1 << 4;
+/// Pause flag: proposal and dispute vote submission and aggregation
+pub const PAUSE_VOTE: u8 = 1 << 5;
+/// Pause flag: resolve_market, initiate_dispute, finalize_market
+pub const PAUSE_RESOLVE: u8 = 1 << 6;
+/// Pause flag: add_liquidity, remove_liquidity
+pub const PAUSE_LIQUIDITY: u8 = 1 << 7;
+/// Every pausable operation
+pub const PAUSE_ALL: u8 = PAUSE_CREATE
+    | PAUSE_BUY
+    | PAUSE_SELL
+    | PAUSE_CLAIM
+    | PAUSE_VOTE
+    | PAUSE_RESOLVE
+    | PAUSE_LIQUIDITY;
+
+/// Trading fee schedule in basis points (100 = 1%, 10000 = 100%)
+///
+/// Read from GlobalConfig, or from a market's snapshot taken at activation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeSchedule {
+    /// Protocol fee
+    pub protocol_fee_bps: u16,
+    /// Resolver reward
+    pub resolver_reward_bps: u16,
+    /// Liquidity provider fee
+    pub liquidity_provider_fee_bps: u16,
+}
+
+/// Role that changed the pause state, recorded in pause events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseAuthority {
+    /// Protocol admin (may pause and unpause)
+    Admin,
+    /// Guardian (may only pause)
+    Guardian,
+}
+
 /// Global configuration for the ZMART protocol
 ///
 /// This account stores protocol-wide settings including fee percentages,
 /// voting thresholds, time limits, and admin controls.
 ///
 /// PDA Seeds: ["global_config"]
-/// Size: 198 bytes (8 discriminator + 190 data)
+/// Size: 206 bytes (8 discriminator + 198 data)
 #[account]
 pub struct GlobalConfig {
     /// Protocol admin (can update parameters)
@@ -51,14 +101,34 @@ pub struct GlobalConfig {
     /// Default: 8000 (80%)
     pub min_resolver_reputation: u16,
 
-    /// Emergency pause flag (stops all trading and state transitions)
-    pub is_paused: bool,
+    /// Paused operations (bitmask of PAUSE_* flags, 0 = nothing paused)
+    ///
+    /// Formerly `is_paused: bool`; a legacy `true` reads as `LEGACY_PAUSED`,
+    /// so use `paused_flags()` rather than this field directly.
+    pub paused_operations: u8,
+
+    /// Admin nominated via propose_admin, awaiting accept_admin
+    /// Pubkey::default() when no handover is pending
+    pub pending_admin: Pubkey,
+
+    /// Guardian: may pause the protocol or freeze a market, but not undo it
+    /// Pubkey::default() when no guardian is set
+    pub guardian: Pubkey,
 
-    /// Reserved space for future upgrades (64 bytes)
-    pub reserved: [u8; 64],
+    // pending_admin and guardian occupy exactly the former 64 reserved
+    // bytes, so `bump` keeps its offset and existing accounts decode with
+    // neither set.
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Claim window after market finalization (in seconds)
+    /// Default: 31536000 (365 days); unclaimed funds can be swept afterwards
+    ///
+    /// Stored after `bump`, in the 8 spare bytes every GlobalConfig account
+    /// was allocated with (`space = 8 + LEN`); existing accounts read it as
+    /// zero (see `claim_window`).
+    pub claim_period: i64,
 }
 
 impl GlobalConfig {
@@ -77,11 +147,13 @@ impl GlobalConfig {
     /// - min_resolution_delay: 8 bytes (i64)
     /// - dispute_period: 8 bytes (i64)
     /// - min_resolver_reputation: 2 bytes (u16)
-    /// - is_paused: 1 byte (bool)
-    /// - reserved: 64 bytes ([u8; 64])
+    /// - paused_operations: 1 byte (u8)
+    /// - pending_admin: 32 bytes (Pubkey)
+    /// - guardian: 32 bytes (Pubkey)
     /// - bump: 1 byte (u8)
+    /// - claim_period: 8 bytes (i64)
     ///
-    /// Total: 8 + 32 + 32 + 32 + 2 + 2 + 2 + 2 + 2 + 8 + 8 + 2 + 1 + 64 + 1 = 198 bytes
+    /// Total: 8 + 32 + 32 + 32 + 2 + 2 + 2 + 2 + 2 + 8 + 8 + 2 + 1 + 32 + 32 + 1 + 8 = 206 bytes
     pub const LEN: usize = 8      // discriminator
         + 32                      // admin
         + 32                      // backend_authority
@@ -94,9 +166,17 @@ impl GlobalConfig {
         + 8                       // min_resolution_delay
         + 8                       // dispute_period
         + 2                       // min_resolver_reputation
-        + 1                       // is_paused
-        + 64                      // reserved
-        + 1;                      // bump
+        + 1                       // paused_operations
+        + 32                      // pending_admin
+        + 32                      // guardian
+        + 1                       // bump
+        + 8;                      // claim_period
+
+    /// Default claim window after finalization (365 days)
+    pub const DEFAULT_CLAIM_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+    /// Shortest claim window the admin can configure (30 days)
+    pub const MIN_CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60;
 
     /// Validate configuration invariants
     ///
@@ -142,10 +222,85 @@ impl GlobalConfig {
             self.dispute_period > 0,
             ErrorCode::InvalidTimeLimit
         );
+        require!(
+            self.claim_period >= Self::MIN_CLAIM_PERIOD,
+            ErrorCode::InvalidTimeLimit
+        );
+
+        Ok(())
+    }
+
+    /// Claim window in seconds
+    ///
+    /// Configs initialized before `claim_period` existed read it as zero from
+    /// reserved space; those fall back to `DEFAULT_CLAIM_PERIOD`.
+    pub fn claim_window(&self) -> i64 {
+        if self.claim_period > 0 {
+            self.claim_period
+        } else {
+            Self::DEFAULT_CLAIM_PERIOD
+        }
+    }
+
+    /// Paused operations as PAUSE_* flags
+    ///
+    /// A config still holding `LEGACY_PAUSED` was paused with the old
+    /// `is_paused` flag, which blocked create, buy and sell.
+    pub fn paused_flags(&self) -> u8 {
+        if self.paused_operations & LEGACY_PAUSED != 0 {
+            (self.paused_operations & PAUSE_ALL) | PAUSE_CREATE | PAUSE_BUY | PAUSE_SELL
+        } else {
+            self.paused_operations
+        }
+    }
+
+    /// Fail if any operation in `operation` (PAUSE_* flags) is paused
+    pub fn require_not_paused(&self, operation: u8) -> Result<()> {
+        require!(
+            self.paused_flags() & operation == 0,
+            ErrorCode::ProtocolPaused
+        );
+        Ok(())
+    }
+
+    /// Role `key` holds for pausing, if any
+    ///
+    /// # Errors
+    /// Returns `Unauthorized` if `key` is neither admin nor guardian
+    pub fn pause_authority(&self, key: &Pubkey) -> Result<PauseAuthority> {
+        if *key == self.admin {
+            Ok(PauseAuthority::Admin)
+        } else if self.guardian != Pubkey::default() && *key == self.guardian {
+            Ok(PauseAuthority::Guardian)
+        } else {
+            err!(ErrorCode::Unauthorized)
+        }
+    }
 
+    /// Set the paused operations (PAUSE_* flags)
+    ///
+    /// Replaces a legacy `LEGACY_PAUSED` value.
+    ///
+    /// # Errors
+    /// Returns `InvalidPauseFlags` if `paused_operations` has unknown bits set
+    pub fn set_paused_operations(&mut self, paused_operations: u8) -> Result<()> {
+        require!(
+            paused_operations & !PAUSE_ALL == 0,
+            ErrorCode::InvalidPauseFlags
+        );
+        self.paused_operations = paused_operations;
         Ok(())
     }
 
+    /// Current trading fee schedule
+    pub fn fee_schedule(&self) -> FeeSchedule {
+        FeeSchedule {
+            protocol_fee_bps: self.protocol_fee_bps,
+            resolver_reward_bps: self.resolver_reward_bps,
+            liquidity_provider_fee_bps: self.liquidity_provider_fee_bps,
+        }
+    }
+
     /// Get total fee percentage in basis points
     pub fn total_fee_bps(&self) -> Result<u16> {
         self.protocol_fee_bps
@@ -184,7 +339,78 @@ mod tests {
     #[test]
     fn test_global_config_size() {
         // Verify calculated size matches actual struct size
-        assert_eq!(GlobalConfig::LEN, 198);
+        assert_eq!(GlobalConfig::LEN, 206);
+    }
+
+    #[test]
+    fn test_decodes_baseline_layout() {
+        // Layout every deployed config was written with (is_paused + 64 reserved bytes)
+        #[derive(AnchorSerialize)]
+        struct BaselineGlobalConfig {
+            admin: Pubkey,
+            backend_authority: Pubkey,
+            protocol_fee_wallet: Pubkey,
+            protocol_fee_bps: u16,
+            resolver_reward_bps: u16,
+            liquidity_provider_fee_bps: u16,
+            proposal_approval_threshold: u16,
+            dispute_success_threshold: u16,
+            min_resolution_delay: i64,
+            dispute_period: i64,
+            min_resolver_reputation: u16,
+            is_paused: bool,
+            reserved: [u8; 64],
+            bump: u8,
+        }
+
+        let baseline = BaselineGlobalConfig {
+            admin: Pubkey::new_unique(),
+            backend_authority: Pubkey::new_unique(),
+            protocol_fee_wallet: Pubkey::new_unique(),
+            protocol_fee_bps: 300,
+            resolver_reward_bps: 200,
+            liquidity_provider_fee_bps: 500,
+            proposal_approval_threshold: 7000,
+            dispute_success_threshold: 6000,
+            min_resolution_delay: 86400,
+            dispute_period: 259200,
+            min_resolver_reputation: 8000,
+            is_paused: false,
+            reserved: [0; 64],
+            bump: 254,
+        };
+
+        // Deployed accounts were allocated 8 + 198 bytes, zero-filled past the data
+        let mut data = GlobalConfig::DISCRIMINATOR.to_vec();
+        data.extend(baseline.try_to_vec().unwrap());
+        data.resize(8 + 198, 0);
+
+        let config = GlobalConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.admin, baseline.admin);
+        assert_eq!(config.min_resolver_reputation, 8000);
+        assert_eq!(config.paused_operations, 0);
+        assert_eq!(config.pending_admin, Pubkey::default());
+        assert_eq!(config.guardian, Pubkey::default());
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.claim_window(), GlobalConfig::DEFAULT_CLAIM_PERIOD);
+
+        // A config paused with the old flag keeps create, buy and sell paused
+        let paused = BaselineGlobalConfig { is_paused: true, ..baseline };
+        let mut data = GlobalConfig::DISCRIMINATOR.to_vec();
+        data.extend(paused.try_to_vec().unwrap());
+        data.resize(8 + 198, 0);
+
+        let mut config = GlobalConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.paused_flags(), PAUSE_CREATE | PAUSE_BUY | PAUSE_SELL);
+        assert!(config.require_not_paused(PAUSE_CREATE).is_err());
+        assert!(config.require_not_paused(PAUSE_BUY).is_err());
+        assert!(config.require_not_paused(PAUSE_SELL).is_err());
+        assert!(config.require_not_paused(PAUSE_CLAIM).is_ok());
+
+        // Setting flags replaces the legacy value
+        config.set_paused_operations(PAUSE_BUY).unwrap();
+        assert_eq!(config.paused_flags(), PAUSE_BUY);
+        assert!(config.require_not_paused(PAUSE_CREATE).is_ok());
     }
 
     #[test]
@@ -230,6 +456,34 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_claim_window() {
+        let mut config = create_test_config();
+        assert_eq!(config.claim_window(), 2_592_000);
+
+        // Legacy configs (zeroed reserved space) use the default
+        config.claim_period = 0;
+        assert_eq!(config.claim_window(), GlobalConfig::DEFAULT_CLAIM_PERIOD);
+        assert!(config.validate().is_err());
+
+        // Too short to leave winners time to claim
+        config.claim_period = GlobalConfig::MIN_CLAIM_PERIOD - 1;
+        assert!(config.validate().is_err());
+        config.claim_period = GlobalConfig::MIN_CLAIM_PERIOD;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_pause_flags_are_independent() {
+        let mut config = create_test_config();
+        assert!(config.require_not_paused(PAUSE_ALL).is_ok());
+
+        config.paused_operations = PAUSE_BUY;
+        assert!(config.require_not_paused(PAUSE_BUY).is_err());
+        assert!(config.require_not_paused(PAUSE_SELL).is_ok());
+        assert!(config.require_not_paused(PAUSE_CLAIM).is_ok());
+    }
+
     #[test]
     fn test_fee_calculation() {
         let config = create_test_config();
@@ -269,8 +523,10 @@ mod tests {
             min_resolution_delay: 86400, // 24 hours
             dispute_period: 259200, // 3 days
             min_resolver_reputation: 8000, // 80%
-            is_paused: false,
-            reserved: [0; 64],
+            paused_operations: 0,
+            claim_period: 2_592_000, // 30 days
+            pending_admin: Pubkey::default(),
+            guardian: Pubkey::default(),
             bump: 255,
         }
     }
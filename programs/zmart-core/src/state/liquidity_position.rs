@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// A third-party liquidity provider's stake in a market
+///
+/// LP shares are minted by add_liquidity and burned by remove_liquidity.
+/// The creator's shares from the initial subsidy are tracked on the market
+/// itself (`MarketAccount::creator_lp_shares`).
+///
+/// PDA Seeds: ["lp-position", market.key(), provider.key()]
+#[account]
+pub struct LiquidityPosition {
+    /// Market this position belongs to
+    pub market: Pubkey,
+
+    /// Liquidity provider's wallet address
+    pub provider: Pubkey,
+
+    /// LP shares held
+    pub lp_shares: u64,
+
+    /// Total lamports deposited
+    pub total_deposited: u64,
+
+    /// Total lamports withdrawn
+    pub total_withdrawn: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LiquidityPosition {
+    /// Calculate exact account size
+    pub const LEN: usize = 8   // discriminator
+        + 32                   // market
+        + 32                   // provider
+        + 8                    // lp_shares
+        + 8                    // total_deposited
+        + 8                    // total_withdrawn
+        + 1;                   // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len_matches_serialized_size() {
+        let position = LiquidityPosition {
+            market: Pubkey::new_unique(),
+            provider: Pubkey::new_unique(),
+            lp_shares: 1,
+            total_deposited: 1,
+            total_withdrawn: 0,
+            bump: 255,
+        };
+        assert_eq!(LiquidityPosition::LEN, 8 + position.try_to_vec().unwrap().len());
+    }
+}
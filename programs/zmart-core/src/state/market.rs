@@ -1,13 +1,16 @@
+use std::ops::{Deref, DerefMut};
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
+use crate::math::{LmsrCurve, LsLmsrCurve, ParimutuelCurve, PricingCurve};
+use crate::state::{FeeSchedule, GlobalConfig, UserPosition};
 
-/// Market lifecycle states (7-state FSM)
+/// Market lifecycle states (8-state FSM)
 ///
 /// State transitions:
 /// PROPOSED → APPROVED → ACTIVE → RESOLVING → DISPUTED → FINALIZED
 ///                              → (skip DISPUTED) → FINALIZED
-/// PROPOSED → CANCELLED (admin only)
-/// APPROVED → CANCELLED (admin only)
+/// PROPOSED → REJECTED (proposal vote below approval threshold)
+/// PROPOSED/APPROVED/ACTIVE/RESOLVING/DISPUTED → CANCELLED (admin only)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
 pub enum MarketState {
@@ -25,6 +28,55 @@ pub enum MarketState {
     Finalized = 5,
     /// Market cancelled by admin (terminal state)
     Cancelled = 6,
+    /// Proposal failed community vote (terminal state, creator can reclaim)
+    Rejected = 7,
+    /// Paid out and retired (terminal state); the account stays as a
+    /// tombstone so its market_id cannot be created again
+    Closed = 8,
+}
+
+/// Market resolution outcome
+///
+/// Discriminants keep the on-chain encoding of the former `Option<bool>`
+/// fields: Some(NO) and Some(YES) serialize exactly like Some(false)/Some(true).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum MarketOutcome {
+    /// NO holders win
+    No = 0,
+    /// YES holders win
+    Yes = 1,
+    /// Question ambiguous or unresolvable, all holders refunded
+    Invalid = 2,
+}
+
+/// Market maker pricing curve, chosen at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum CurveType {
+    /// LMSR with fixed liquidity `b_parameter`
+    Lmsr = 0,
+    /// Liquidity-sensitive LMSR: b = alpha * (shares_yes + shares_no)
+    LsLmsr = 1,
+    /// Parimutuel pool: stakes are 1:1 shares, winners split the pool
+    Parimutuel = 2,
+}
+
+/// How a market accounts for the refunds it owes on CANCELLED or INVALID
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum RefundAccounting {
+    /// Created before net contributions were tracked (zeroed reserved bytes):
+    /// positions are refunded their `total_invested` while the market can
+    /// pay, and `total_net_contribution` is meaningless until seeded
+    Legacy = 0,
+    /// `total_net_contribution` is the exact sum of unrefunded net
+    /// contributions, shared out pro-rata
+    NetContribution = 1,
+    /// Legacy market whose `total_net_contribution` was seeded with
+    /// `total_volume` (an upper bound on what its positions paid in), less
+    /// refunds paid since
+    LegacySeeded = 2,
 }
 
 /// Individual prediction market account
@@ -47,6 +99,7 @@ pub struct MarketAccount {
 
     /// LMSR liquidity depth parameter (fixed-point, 9 decimals)
     /// Controls price sensitivity: larger b = less sensitive
+    /// For LS-LMSR markets this is the depth at creation (b grows with volume)
     pub b_parameter: u64,
 
     /// Initial liquidity provided at market creation (in lamports)
@@ -94,11 +147,11 @@ pub struct MarketAccount {
     /// Resolver's wallet address
     pub resolver: Pubkey,
 
-    /// Proposed outcome (Some(true)=YES, Some(false)=NO, None=INVALID)
-    pub proposed_outcome: Option<bool>,
+    /// Proposed outcome (None until resolve_market is called)
+    pub proposed_outcome: Option<MarketOutcome>,
 
-    /// Final outcome after dispute period
-    pub final_outcome: Option<bool>,
+    /// Final outcome after dispute period (None until finalized)
+    pub final_outcome: Option<MarketOutcome>,
 
     /// IPFS hash of resolution evidence (46 bytes for CIDv0: Qm...)
     pub ipfs_evidence_hash: [u8; 46],
@@ -179,12 +232,71 @@ pub struct MarketAccount {
     pub cancelled_at: Option<i64>,
 
     /// Reentrancy guard (SECURITY: Finding #8)
-    /// Set to true during sensitive operations to prevent concurrent access
+    /// Set by `MarketLock` while a lamport-moving handler runs
     /// Prevents reentrancy attacks during lamport transfers
     pub is_locked: bool,
 
-    /// Reserved space for future upgrades (119 bytes, reduced by 1 for is_locked)
-    pub reserved: [u8; 119],
+    // ============================================================
+    // Resolver Payout
+    // ============================================================
+
+    /// Resolver has withdrawn `accumulated_resolver_fees` via claim_resolver_fees
+    pub resolver_fees_claimed: bool,
+
+    // ============================================================
+    // Refund Accounting
+    // ============================================================
+
+    /// Sum of all positions' `net_contribution` still owed a refund (lamports)
+    /// Denominator for pro-rata INVALID refunds; decremented as refunds are paid
+    pub total_net_contribution: u64,
+
+    /// Outcome the dispute initiator claims is correct, as a fixed-width tag
+    /// (0 = none recorded, otherwise MarketOutcome + 1; see `dispute_outcome()`)
+    /// Becomes the final outcome if the dispute succeeds
+    pub dispute_outcome: u8,
+
+    /// Positions not yet settled (claimed, refunded, or closed)
+    /// close_market is allowed once this reaches zero
+    pub open_positions: u32,
+
+    /// Fees were frozen into `fee_snapshot` at activation
+    /// (false = follow GlobalConfig)
+    pub has_fee_snapshot: bool,
+
+    /// Fee schedule frozen at activation (ignored unless `has_fee_snapshot`)
+    pub fee_snapshot: FeeSchedule,
+
+    /// Admin freeze: blocks trading, claims, voting and resolution on this market
+    pub is_frozen: bool,
+
+    /// Pricing curve (zeroed reserved bytes decode as Lmsr)
+    pub curve_type: CurveType,
+
+    /// LS-LMSR liquidity sensitivity α (fixed-point, 9 decimals; 0 for LMSR)
+    pub alpha: u64,
+
+    /// LP shares outstanding, minted 1:1 with the creation subsidy
+    /// 0 for markets that predate LP shares (the pool belongs to the creator)
+    pub total_lp_shares: u64,
+
+    /// Portion of `total_lp_shares` held by the creator
+    /// (third parties hold LiquidityPosition accounts)
+    pub creator_lp_shares: u64,
+
+    /// Refund basis (zeroed reserved bytes decode as Legacy)
+    pub refund_accounting: RefundAccounting,
+
+    /// `open_positions` has counted every position since creation
+    /// (false for markets created before the counter existed)
+    pub tracks_open_positions: bool,
+
+    /// Claims close at this timestamp, fixed at finalization
+    /// (0 for markets finalized before deadlines were stored)
+    pub claim_deadline: i64,
+
+    /// Reserved space for future upgrades (62 bytes)
+    pub reserved: [u8; 62],
 
     /// Bump seed for PDA derivation
     pub bump: u8,
@@ -198,14 +310,47 @@ impl MarketAccount {
     /// Total: 480 bytes (472 original + cancelled_at Option<i64> 16 bytes - reserved 8 bytes = +8 net)
     pub const LEN: usize = 480;
 
+    /// Proposal voting window (7 days) before an unapproved proposal counts as rejected
+    pub const PROPOSAL_VOTING_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+    /// Write a market loaded with `try_load_open` back to its account
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Load a market account that may already have been closed
+    ///
+    /// Markets reclaimed before tombstones were introduced were closed,
+    /// leaving them empty and owned by the system program. Returns `None` in
+    /// that case so dependent accounts (positions, vote records) can still be
+    /// closed.
+    ///
+    /// # Errors
+    /// Returns `AccountOwnedByWrongProgram` if a non-empty account is not owned
+    /// by this program, or a deserialization error if it is not a market
+    pub fn try_load_open(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+
+        require_keys_eq!(
+            *info.owner,
+            crate::ID,
+            anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+        );
+
+        let data = info.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
     /// Lock the market for reentrancy protection (SECURITY: Finding #8)
     ///
-    /// This must be called before any sensitive operation that transfers lamports.
-    /// Prevents concurrent or reentrant calls to the same market.
+    /// Only called through `MarketLock`, which guarantees the matching unlock.
     ///
     /// # Errors
     /// Returns `ErrorCode::Reentrant` if market is already locked
-    pub fn lock(&mut self) -> Result<()> {
+    fn lock(&mut self) -> Result<()> {
         require!(!self.is_locked, ErrorCode::Reentrant);
         self.is_locked = true;
         msg!("Market locked for operation");
@@ -213,10 +358,7 @@ impl MarketAccount {
     }
 
     /// Unlock the market after operation completes (SECURITY: Finding #8)
-    ///
-    /// This must be called after the sensitive operation finishes.
-    /// Allows subsequent operations to proceed.
-    pub fn unlock(&mut self) {
+    fn unlock(&mut self) {
         self.is_locked = false;
         msg!("Market unlocked");
     }
@@ -230,7 +372,7 @@ impl MarketAccount {
     /// Returns `ErrorCode::InvalidReservedField` if reserved contains non-zero bytes
     pub fn validate_reserved(&self) -> Result<()> {
         require!(
-            self.reserved == [0; 119],
+            self.reserved == [0; 62],
             ErrorCode::InvalidReservedField
         );
         Ok(())
@@ -240,28 +382,34 @@ impl MarketAccount {
     ///
     /// Valid transitions:
     /// - PROPOSED → APPROVED
+    /// - PROPOSED → REJECTED
     /// - APPROVED → ACTIVE
     /// - ACTIVE → RESOLVING
     /// - RESOLVING → DISPUTED
     /// - RESOLVING → FINALIZED
     /// - DISPUTED → FINALIZED
+    /// - any non-terminal state → CANCELLED
+    /// - PROPOSED, REJECTED, CANCELLED or FINALIZED → CLOSED
     pub fn can_transition_to(&self, new_state: MarketState) -> bool {
         use MarketState::*;
         matches!(
             (self.state, new_state),
             (Proposed, Approved)
+                | (Proposed, Rejected)
                 | (Approved, Active)
                 | (Active, Resolving)
                 | (Resolving, Disputed)
                 | (Resolving, Finalized)
                 | (Disputed, Finalized)
+                | (Proposed | Approved | Active | Resolving | Disputed, Cancelled)
+                | (Proposed | Rejected | Cancelled | Finalized, Closed)
         )
     }
 
     /// SECURITY FIX (Finding #6 - Week 3): Transition state with validation
     ///
     /// Wrapper around state transitions that enforces the state machine.
-    /// Prevents invalid state transitions that could bypass the 6-state FSM.
+    /// Prevents invalid state transitions that could bypass the 8-state FSM.
     ///
     /// Valid transitions:
     /// - PROPOSED → APPROVED
@@ -270,6 +418,8 @@ impl MarketAccount {
     /// - RESOLVING → DISPUTED
     /// - RESOLVING → FINALIZED
     /// - DISPUTED → FINALIZED
+    /// - any non-terminal state → CANCELLED
+    /// - PROPOSED, REJECTED, CANCELLED or FINALIZED → CLOSED
     ///
     /// # Arguments
     /// * `new_state` - The target state to transition to
@@ -287,6 +437,27 @@ impl MarketAccount {
         Ok(())
     }
 
+    /// Retire a market whose lamports have been paid out
+    ///
+    /// The account is kept as a CLOSED tombstone instead of being closed:
+    /// its PDA derives from the creator-chosen market_id, so a closed account
+    /// could be created again, and positions and vote records left from the
+    /// old market would attach to the new one. The pool accounting is
+    /// cleared; the rest stays as the on-chain record.
+    pub fn retire(&mut self) -> Result<()> {
+        self.transition_state(MarketState::Closed)?;
+        self.current_liquidity = 0;
+        self.accumulated_protocol_fees = 0;
+        self.accumulated_resolver_fees = 0;
+        self.accumulated_lp_fees = 0;
+        self.resolver_fees_claimed = true;
+        self.total_net_contribution = 0;
+        self.open_positions = 0;
+        self.total_lp_shares = 0;
+        self.creator_lp_shares = 0;
+        Ok(())
+    }
+
     /// Check if proposal voting passed the threshold
     ///
     /// Requires: proposal_total_votes > 0
@@ -300,6 +471,16 @@ impl MarketAccount {
         approval_rate >= threshold_bps as u64
     }
 
+    /// Check if proposal should be rejected
+    ///
+    /// A proposal is rejected once its voting window has elapsed without
+    /// reaching `threshold_bps` (zero votes counts as not approved).
+    pub fn proposal_rejected(&self, threshold_bps: u16, now: i64) -> bool {
+        self.state == MarketState::Proposed
+            && now >= self.created_at.saturating_add(Self::PROPOSAL_VOTING_PERIOD)
+            && !self.proposal_approved(threshold_bps)
+    }
+
     /// Check if dispute succeeded (≥60% agree by default)
     ///
     /// Requires: dispute_total_votes > 0
@@ -337,6 +518,409 @@ impl MarketAccount {
         self.current_liquidity
     }
 
+    /// Resolver fees still owed to `market.resolver` (call after finalization)
+    ///
+    /// Returns 0 once the fees have been claimed, or when the final outcome is
+    /// INVALID: an invalid resolution earns no reward, so the resolver pool stays
+    /// in the market and is returned to traders through their refunds.
+    pub fn unclaimed_resolver_fees(&self) -> u64 {
+        match self.final_outcome {
+            Some(MarketOutcome::Yes | MarketOutcome::No) if !self.resolver_fees_claimed => {
+                self.accumulated_resolver_fees
+            }
+            _ => 0,
+        }
+    }
+
+    /// Resolver fees claim_resolver_fees pays out at `now`
+    ///
+    /// # Errors
+    /// * `MarketPaused` - If the market is frozen
+    /// * `ClaimPeriodEnded` - If the claim window after finalization has expired
+    /// * `NoResolverFees` - If nothing is owed (see `unclaimed_resolver_fees`)
+    pub fn claimable_resolver_fees(&self, claim_period: i64, now: i64) -> Result<u64> {
+        self.require_not_frozen()?;
+        require!(!self.claim_period_ended(claim_period, now), ErrorCode::ClaimPeriodEnded);
+
+        let amount = self.unclaimed_resolver_fees();
+        require!(amount > 0, ErrorCode::NoResolverFees);
+        Ok(amount)
+    }
+
+    /// Outcome the dispute initiator claims is correct (None if not recorded)
+    pub fn dispute_outcome(&self) -> Option<MarketOutcome> {
+        match self.dispute_outcome {
+            1 => Some(MarketOutcome::No),
+            2 => Some(MarketOutcome::Yes),
+            3 => Some(MarketOutcome::Invalid),
+            _ => None,
+        }
+    }
+
+    /// Record the outcome the dispute initiator claims is correct
+    pub fn set_dispute_outcome(&mut self, outcome: MarketOutcome) {
+        self.dispute_outcome = outcome as u8 + 1;
+    }
+
+    /// Outcome that stands once dispute votes are counted
+    ///
+    /// A successful dispute replaces the proposed outcome with the initiator's
+    /// `dispute_outcome`, so any of YES/NO/INVALID can move to any other.
+    /// A failed dispute keeps the proposed outcome.
+    ///
+    /// Markets already DISPUTED when `dispute_outcome` was introduced have
+    /// none recorded; those fall back to the proposed outcome under the rule
+    /// they were disputed with (YES and NO flip, anything else stands).
+    pub fn outcome_after_dispute(&self, dispute_succeeded: bool) -> Option<MarketOutcome> {
+        if !dispute_succeeded {
+            return self.proposed_outcome;
+        }
+        self.dispute_outcome().or(match self.proposed_outcome {
+            Some(MarketOutcome::Yes) => Some(MarketOutcome::No),
+            Some(MarketOutcome::No) => Some(MarketOutcome::Yes),
+            proposed => proposed,
+        })
+    }
+
+    /// Refunds still owed to traders (lamports)
+    ///
+    /// CANCELLED and INVALID markets owe each position its net contribution,
+    /// so `total_net_contribution` must stay in the market until claimed.
+    /// Other outcomes owe nothing through the refund path.
+    ///
+    /// Legacy markets never recorded net contributions, so until a refund
+    /// seeds their total they reserve `total_volume`, which bounds what
+    /// their positions paid in.
+    pub fn outstanding_refunds(&self) -> u64 {
+        if self.state != MarketState::Cancelled
+            && self.final_outcome != Some(MarketOutcome::Invalid)
+        {
+            return 0;
+        }
+
+        match self.refund_accounting {
+            RefundAccounting::Legacy => self.total_volume,
+            RefundAccounting::NetContribution | RefundAccounting::LegacySeeded => {
+                self.total_net_contribution
+            }
+        }
+    }
+
+    /// Refund owed to `position` on a CANCELLED or INVALID market, given
+    /// the lamports `available` for payouts
+    ///
+    /// Markets that track net contributions split `available` pro-rata (see
+    /// `UserPosition::pro_rata_refund`). Legacy positions have no net
+    /// contribution recorded, so they keep the refund they were opened
+    /// under: their `total_invested`, as far as the market can pay.
+    pub fn refund_owed(&self, position: &UserPosition, available: u64) -> Result<u64> {
+        match self.refund_accounting {
+            RefundAccounting::NetContribution => {
+                position.pro_rata_refund(self.total_net_contribution, available)
+            }
+            RefundAccounting::Legacy | RefundAccounting::LegacySeeded => {
+                Ok(position.total_invested.min(available))
+            }
+        }
+    }
+
+    /// Refund claim_cancellation_refund pays `position`
+    ///
+    /// # Errors
+    /// * `InvalidMarketState` - If the market is not CANCELLED
+    /// * `MarketPaused` - If the market is frozen
+    /// * `NoWinnings` - If nothing is owed (see `refund_owed`)
+    pub fn cancellation_refund(&self, position: &UserPosition, available: u64) -> Result<u64> {
+        require!(self.state == MarketState::Cancelled, ErrorCode::InvalidMarketState);
+        self.require_not_frozen()?;
+
+        let refund = self.refund_owed(position, available)?;
+        require!(refund > 0, ErrorCode::NoWinnings);
+        Ok(refund)
+    }
+
+    /// Remove a refunded position from the refund denominator
+    ///
+    /// Later claimers then split what remains of the pool between the
+    /// positions still unrefunded. A legacy market is seeded on its first
+    /// refund and then drawn down by each refunded `total_invested`.
+    pub fn release_refund(&mut self, position: &UserPosition) -> Result<()> {
+        self.seed_legacy_refunds();
+        self.total_net_contribution = match self.refund_accounting {
+            RefundAccounting::LegacySeeded => {
+                self.total_net_contribution.saturating_sub(position.total_invested)
+            }
+            _ => self.total_net_contribution
+                .checked_sub(position.net_contribution)
+                .ok_or(ErrorCode::UnderflowError)?,
+        };
+        Ok(())
+    }
+
+    /// Replace a legacy market's untracked refund total with `total_volume`
+    pub fn seed_legacy_refunds(&mut self) {
+        if self.refund_accounting == RefundAccounting::Legacy {
+            self.total_net_contribution = self.total_volume;
+            self.refund_accounting = RefundAccounting::LegacySeeded;
+        }
+    }
+
+    /// Record that a position was settled (claimed, refunded, or closed)
+    ///
+    /// Saturates so markets created before `open_positions` existed (counter
+    /// starts at zero) can still settle their positions.
+    pub fn settle_position(&mut self) {
+        self.open_positions = self.open_positions.saturating_sub(1);
+    }
+
+    /// Whether every position has been settled
+    ///
+    /// Markets created before `open_positions` existed cannot tell, so they
+    /// never report settled; they wait for the claim deadline instead.
+    pub fn positions_settled(&self) -> bool {
+        self.tracks_open_positions && self.open_positions == 0
+    }
+
+    /// Record the final outcome and fix the claim deadline
+    ///
+    /// The deadline is stored rather than derived from the current
+    /// `GlobalConfig::claim_period`, so a later config change only applies
+    /// to markets finalized after it.
+    pub fn finalize(&mut self, outcome: MarketOutcome, now: i64, claim_period: i64) -> Result<()> {
+        self.transition_state(MarketState::Finalized)?;
+        self.final_outcome = Some(outcome);
+        self.finalized_at = now;
+        self.claim_deadline = now
+            .checked_add(claim_period)
+            .ok_or(ErrorCode::OverflowError)?;
+        Ok(())
+    }
+
+    /// Check if the claim window after finalization has expired
+    ///
+    /// `claim_period` comes from `GlobalConfig::claim_window()` and only
+    /// applies to markets finalized before `claim_deadline` was stored.
+    pub fn claim_period_ended(&self, claim_period: i64, now: i64) -> bool {
+        let deadline = if self.claim_deadline > 0 {
+            self.claim_deadline
+        } else {
+            self.finalized_at.saturating_add(claim_period)
+        };
+        self.state == MarketState::Finalized && now >= deadline
+    }
+
+    /// Check if a finalized market can be closed and its remainder swept
+    ///
+    /// Every LP share must have been redeemed first: LP deposits are not
+    /// forfeited by the claim deadline. Before the deadline every position
+    /// must also be settled, the creator must have withdrawn liquidity, and
+    /// the resolver must have been paid. After the deadline, anything left
+    /// unclaimed is swept.
+    pub fn can_close(&self, claim_period: i64, now: i64) -> bool {
+        if self.state != MarketState::Finalized || self.total_lp_shares > 0 {
+            return false;
+        }
+
+        if self.claim_period_ended(claim_period, now) {
+            return true;
+        }
+
+        self.positions_settled()
+            && self.current_liquidity == 0
+            && self.unclaimed_resolver_fees() == 0
+    }
+
+    /// Pricing curve selected by `curve_type`
+    pub fn pricing_curve(&self) -> Box<dyn PricingCurve> {
+        match self.curve_type {
+            CurveType::Lmsr => Box::new(LmsrCurve { b: self.b_parameter }),
+            CurveType::LsLmsr => Box::new(LsLmsrCurve {
+                alpha: self.alpha,
+                initial_b: self.b_parameter,
+            }),
+            CurveType::Parimutuel => Box::new(ParimutuelCurve),
+        }
+    }
+
+    /// Check the creator's loss stays within the curve's bound
+    ///
+    /// LMSR markets are bounded by b * ln(2); LS-LMSR markets by the cost
+    /// of their initial share state (see `PricingCurve::max_loss`).
+    ///
+    /// # Errors
+    /// Returns `BoundedLossExceeded` if current liquidity has fallen more than
+    /// the bound below the initial subsidy
+    pub fn verify_bounded_loss(&self) -> Result<()> {
+        let max_loss = self.pricing_curve().max_loss()?;
+        require!(
+            self.initial_liquidity.saturating_sub(self.current_liquidity) <= max_loss,
+            ErrorCode::BoundedLossExceeded
+        );
+        Ok(())
+    }
+
+    /// Whether liquidity can be added or removed at par
+    ///
+    /// Before activation no trader holds shares, so b and the initial share
+    /// inventory can be rescaled without moving prices. Once trading starts,
+    /// rescaling b under outstanding trader shares would reprice them, so
+    /// LPs stay in until the market settles.
+    pub fn is_pre_trading(&self) -> bool {
+        matches!(self.state, MarketState::Proposed | MarketState::Approved)
+    }
+
+    /// Lamports split pro-rata among winning shares of a YES/NO market
+    ///
+    /// The pool net of the resolver's fees and, except for parimutuel
+    /// markets (which have no LPs), the LP fees. Claims do not change any
+    /// of these, so every winner is paid from the same basis.
+    pub fn winners_pool(&self) -> u64 {
+        let pool = self.current_liquidity.saturating_sub(self.accumulated_resolver_fees);
+        if self.curve_type == CurveType::Parimutuel {
+            pool
+        } else {
+            pool.saturating_sub(self.accumulated_lp_fees)
+        }
+    }
+
+    /// Whether winners no longer have a claim on the pool
+    ///
+    /// True once every position is settled or the claim window has closed
+    /// on a YES/NO market, and always for markets that pay refunds instead
+    /// (those are reserved by `outstanding_refunds`). LP redemption waits
+    /// for this, since winners are paid from `winners_pool`, which includes
+    /// the subsidy.
+    pub fn winners_settled(&self, claim_period: i64, now: i64) -> bool {
+        match self.final_outcome {
+            Some(MarketOutcome::Yes | MarketOutcome::No) => {
+                self.positions_settled() || self.claim_period_ended(claim_period, now)
+            }
+            _ => true,
+        }
+    }
+
+    /// Lamports liquidity providers can withdraw from a settled market
+    ///
+    /// Keeps the rent reserve plus a 10,000 lamport safety margin, the
+    /// resolver's unclaimed fees, and trader refunds still owed. Unpaid
+    /// winnings are not reserved: callers must check `winners_settled`
+    /// first. What remains is the LP fees plus whatever of the pool winners
+    /// did not take (the market maker's own initial shares and rounding).
+    pub fn lp_withdrawable(&self, balance: u64, rent_reserve: u64) -> u64 {
+        let safe_reserve = rent_reserve
+            .saturating_add(10_000)
+            .saturating_add(self.unclaimed_resolver_fees())
+            .saturating_add(self.outstanding_refunds());
+        balance.saturating_sub(safe_reserve)
+    }
+
+    /// Deposit `amount` lamports before trading, returning the LP shares minted
+    ///
+    /// Shares are minted at the current lamports-per-share rate, and b and
+    /// the initial share inventory grow by the same factor as the pool, so
+    /// the worst-case loss stays covered and prices stay at 50/50.
+    ///
+    /// # Errors
+    /// * `LiquidityNotSupported` - Parimutuel markets have no pool to deepen
+    /// * `InvalidMarketState` - Trading has started
+    /// * `InvalidBParameter` - An LMSR market's b would exceed MAX_B
+    pub fn add_liquidity(&mut self, amount: u64) -> Result<u64> {
+        require!(self.curve_type != CurveType::Parimutuel, ErrorCode::LiquidityNotSupported);
+        require!(self.is_pre_trading(), ErrorCode::InvalidMarketState);
+        require!(amount > 0, ErrorCode::ZeroAmount);
+
+        // Markets created before LP shares: the creator owns the whole pool
+        if self.total_lp_shares == 0 {
+            self.total_lp_shares = self.current_liquidity;
+            self.creator_lp_shares = self.current_liquidity;
+        }
+
+        let minted = mul_div(amount, self.total_lp_shares, self.current_liquidity)?;
+        require!(minted > 0, ErrorCode::InvalidLiquidity);
+
+        let new_liquidity = self.current_liquidity
+            .checked_add(amount)
+            .ok_or(ErrorCode::OverflowError)?;
+        self.scale_liquidity(new_liquidity)?;
+        if self.curve_type == CurveType::Lmsr {
+            require!(self.b_parameter <= crate::math::MAX_B, ErrorCode::InvalidBParameter);
+        }
+
+        self.total_lp_shares = self.total_lp_shares
+            .checked_add(minted)
+            .ok_or(ErrorCode::OverflowError)?;
+        Ok(minted)
+    }
+
+    /// Burn `lp_shares` before trading, returning the lamports they redeem
+    ///
+    /// The inverse of `add_liquidity`: the pool, b and the initial share
+    /// inventory shrink by the redeemed fraction.
+    pub fn remove_liquidity_at_par(&mut self, lp_shares: u64) -> Result<u64> {
+        require!(self.is_pre_trading(), ErrorCode::InvalidMarketState);
+        require!(
+            lp_shares > 0 && lp_shares < self.total_lp_shares,
+            ErrorCode::InvalidLiquidity
+        );
+
+        let amount = mul_div(lp_shares, self.current_liquidity, self.total_lp_shares)?;
+        self.scale_liquidity(self.current_liquidity - amount)?;
+        self.total_lp_shares -= lp_shares;
+        Ok(amount)
+    }
+
+    /// Burn `lp_shares` after settlement, returning their pro-rata share of
+    /// `withdrawable` (see `lp_withdrawable`)
+    ///
+    /// Every redemption takes the same fraction of what is left, so LPs are
+    /// paid alike regardless of order. Once the last share is burned the
+    /// pool's liquidity and LP fee accounting are cleared, which is why
+    /// redemption must wait for `winners_settled`.
+    pub fn redeem_lp_shares(&mut self, lp_shares: u64, withdrawable: u64) -> Result<u64> {
+        require!(
+            lp_shares > 0 && lp_shares <= self.total_lp_shares,
+            ErrorCode::InvalidLiquidity
+        );
+
+        let amount = mul_div(lp_shares, withdrawable, self.total_lp_shares)?;
+        self.total_lp_shares -= lp_shares;
+        if self.total_lp_shares == 0 {
+            self.current_liquidity = 0;
+            self.accumulated_lp_fees = 0;
+        }
+        Ok(amount)
+    }
+
+    /// Rescale the pre-trading pool to `new_liquidity` lamports, scaling b
+    /// and the initial share inventory by the same factor
+    fn scale_liquidity(&mut self, new_liquidity: u64) -> Result<()> {
+        let old_liquidity = self.current_liquidity;
+        self.b_parameter = mul_div(self.b_parameter, new_liquidity, old_liquidity)?;
+        self.shares_yes = mul_div(self.shares_yes, new_liquidity, old_liquidity)?;
+        self.shares_no = mul_div(self.shares_no, new_liquidity, old_liquidity)?;
+        self.initial_liquidity = new_liquidity;
+        self.current_liquidity = new_liquidity;
+        Ok(())
+    }
+
+    /// Fail if the market has been frozen
+    pub fn require_not_frozen(&self) -> Result<()> {
+        require!(!self.is_frozen, ErrorCode::MarketPaused);
+        Ok(())
+    }
+
+    /// Fees charged on trades in this market
+    ///
+    /// Markets that snapshotted fees at activation keep them; others follow
+    /// the current GlobalConfig.
+    pub fn fee_schedule(&self, config: &GlobalConfig) -> FeeSchedule {
+        if self.has_fee_snapshot {
+            self.fee_snapshot
+        } else {
+            config.fee_schedule()
+        }
+    }
+
     /// Calculate total fees accumulated
     pub fn total_fees_accumulated(&self) -> Result<u64> {
         self.accumulated_protocol_fees
@@ -346,6 +930,125 @@ impl MarketAccount {
     }
 }
 
+/// Reentrancy guard over a market (SECURITY: Finding #8)
+///
+/// Acquiring sets `is_locked` and dropping clears it, so the market is
+/// unlocked on every exit path, early returns included. Lamport-moving
+/// handlers acquire it up front and work through the guard, which derefs to
+/// the wrapped market (usually an `Account<MarketAccount>`).
+///
+/// ```ignore
+/// let mut market = MarketLock::acquire(&mut ctx.accounts.market)?;
+/// transfer_from_pda_with_data(&market.to_account_info(), &to, amount)?;
+/// market.current_liquidity = 0;
+/// // unlocked here, before Anchor writes the account back
+/// ```
+pub struct MarketLock<'a, T: DerefMut<Target = MarketAccount>> {
+    market: &'a mut T,
+}
+
+impl<'a, T: DerefMut<Target = MarketAccount>> MarketLock<'a, T> {
+    /// Lock `market` until the guard is dropped
+    ///
+    /// # Errors
+    /// Returns `ErrorCode::Reentrant` if market is already locked
+    pub fn acquire(market: &'a mut T) -> Result<Self> {
+        market.lock()?;
+        Ok(Self { market })
+    }
+}
+
+impl<T: DerefMut<Target = MarketAccount>> Deref for MarketLock<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.market
+    }
+}
+
+impl<T: DerefMut<Target = MarketAccount>> DerefMut for MarketLock<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.market
+    }
+}
+
+impl<T: DerefMut<Target = MarketAccount>> Drop for MarketLock<'_, T> {
+    fn drop(&mut self) {
+        self.market.unlock();
+    }
+}
+
+/// value * numerator / denominator in 128-bit arithmetic
+fn mul_div(value: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    require!(denominator > 0, ErrorCode::DivisionByZero);
+    let result = (value as u128) * (numerator as u128) / (denominator as u128);
+    u64::try_from(result).map_err(|_| ErrorCode::OverflowError.into())
+}
+
+#[cfg(test)]
+impl MarketAccount {
+    /// Blank PROPOSED LMSR market for unit tests
+    ///
+    /// Tests set the fields they exercise with struct update syntax.
+    pub(crate) fn test_market() -> Self {
+        MarketAccount {
+            market_id: [0; 32],
+            creator: Pubkey::new_unique(),
+            state: MarketState::Proposed,
+            b_parameter: 1000 * 1_000_000_000, // 1000 SOL equivalent
+            initial_liquidity: 10_000_000_000, // 10 SOL
+            current_liquidity: 10_000_000_000,
+            shares_yes: 0,
+            shares_no: 0,
+            total_volume: 0,
+            created_at: 0,
+            approved_at: 0,
+            activated_at: 0,
+            resolution_proposed_at: 0,
+            resolved_at: 0,
+            finalized_at: 0,
+            resolver: Pubkey::default(),
+            proposed_outcome: None,
+            final_outcome: None,
+            ipfs_evidence_hash: [0; 46],
+            dispute_initiated_at: 0,
+            dispute_initiator: Pubkey::default(),
+            accumulated_protocol_fees: 0,
+            accumulated_resolver_fees: 0,
+            accumulated_lp_fees: 0,
+            proposal_likes: 0,
+            proposal_dislikes: 0,
+            proposal_total_votes: 0,
+            resolution_agree: 0,
+            resolution_disagree: 0,
+            resolution_total_votes: 0,
+            dispute_agree: 0,
+            dispute_disagree: 0,
+            dispute_total_votes: 0,
+            was_disputed: false,
+            is_cancelled: false,
+            cancelled_at: None,
+            is_locked: false,
+            resolver_fees_claimed: false,
+            total_net_contribution: 0,
+            dispute_outcome: 0,
+            open_positions: 0,
+            has_fee_snapshot: false,
+            fee_snapshot: FeeSchedule::default(),
+            is_frozen: false,
+            curve_type: CurveType::Lmsr,
+            alpha: 0,
+            total_lp_shares: 0,
+            creator_lp_shares: 0,
+            refund_accounting: RefundAccounting::NetContribution,
+            tracks_open_positions: true,
+            claim_deadline: 0,
+            reserved: [0; 62],
+            bump: 255,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,9 +1063,123 @@ mod tests {
             MarketAccount::LEN, actual_size);
     }
 
+    #[test]
+    fn test_decodes_baseline_layout() {
+        // Layout every deployed market was written with (119 reserved bytes)
+        #[derive(AnchorSerialize)]
+        struct BaselineMarketAccount {
+            market_id: [u8; 32],
+            creator: Pubkey,
+            state: MarketState,
+            b_parameter: u64,
+            initial_liquidity: u64,
+            current_liquidity: u64,
+            shares_yes: u64,
+            shares_no: u64,
+            total_volume: u64,
+            created_at: i64,
+            approved_at: i64,
+            activated_at: i64,
+            resolution_proposed_at: i64,
+            resolved_at: i64,
+            finalized_at: i64,
+            resolver: Pubkey,
+            proposed_outcome: Option<bool>,
+            final_outcome: Option<bool>,
+            ipfs_evidence_hash: [u8; 46],
+            dispute_initiated_at: i64,
+            dispute_initiator: Pubkey,
+            accumulated_protocol_fees: u64,
+            accumulated_resolver_fees: u64,
+            accumulated_lp_fees: u64,
+            proposal_likes: u32,
+            proposal_dislikes: u32,
+            proposal_total_votes: u32,
+            resolution_agree: u32,
+            resolution_disagree: u32,
+            resolution_total_votes: u32,
+            dispute_agree: u32,
+            dispute_disagree: u32,
+            dispute_total_votes: u32,
+            was_disputed: bool,
+            is_cancelled: bool,
+            cancelled_at: Option<i64>,
+            is_locked: bool,
+            reserved: [u8; 119],
+            bump: u8,
+        }
+
+        let baseline = BaselineMarketAccount {
+            market_id: [7; 32],
+            creator: Pubkey::new_unique(),
+            state: MarketState::Disputed,
+            b_parameter: 1_000_000_000_000,
+            initial_liquidity: 10_000_000_000,
+            current_liquidity: 12_000_000_000,
+            shares_yes: 3_000_000_000,
+            shares_no: 1_000_000_000,
+            total_volume: 2_000_000_000,
+            created_at: 1_000,
+            approved_at: 2_000,
+            activated_at: 3_000,
+            resolution_proposed_at: 4_000,
+            resolved_at: 0,
+            finalized_at: 0,
+            resolver: Pubkey::new_unique(),
+            proposed_outcome: Some(true),
+            final_outcome: None,
+            ipfs_evidence_hash: [1; 46],
+            dispute_initiated_at: 5_000,
+            dispute_initiator: Pubkey::new_unique(),
+            accumulated_protocol_fees: 60_000_000,
+            accumulated_resolver_fees: 40_000_000,
+            accumulated_lp_fees: 100_000_000,
+            proposal_likes: 80,
+            proposal_dislikes: 20,
+            proposal_total_votes: 100,
+            resolution_agree: 0,
+            resolution_disagree: 0,
+            resolution_total_votes: 0,
+            dispute_agree: 0,
+            dispute_disagree: 0,
+            dispute_total_votes: 0,
+            was_disputed: false,
+            is_cancelled: false,
+            cancelled_at: None,
+            is_locked: false,
+            reserved: [0; 119],
+            bump: 253,
+        };
+
+        // Deployed accounts were allocated 8 + LEN bytes, zero-filled past the data
+        let mut data = MarketAccount::DISCRIMINATOR.to_vec();
+        data.extend(baseline.try_to_vec().unwrap());
+        data.resize(8 + MarketAccount::LEN, 0);
+
+        let market = MarketAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(market.bump, 253);
+        assert_eq!(market.market_id, [7; 32]);
+        assert_eq!(market.proposed_outcome, Some(MarketOutcome::Yes));
+        assert_eq!(market.dispute_outcome(), None);
+        assert_eq!(market.outcome_after_dispute(true), Some(MarketOutcome::No));
+        assert!(!market.has_fee_snapshot);
+        assert_eq!(market.curve_type, CurveType::Lmsr);
+        assert_eq!(market.total_lp_shares, 0);
+        assert_eq!(market.refund_accounting, RefundAccounting::Legacy);
+        assert!(!market.tracks_open_positions);
+        assert_eq!(market.claim_deadline, 0);
+        assert!(market.validate_reserved().is_ok());
+
+        // Carved-out fields take exactly the bytes reserved gave up
+        assert_eq!(
+            market.try_to_vec().unwrap().len(),
+            baseline.try_to_vec().unwrap().len()
+        );
+    }
+
     #[test]
     fn test_state_transitions() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         // Valid transitions
         market.state = MarketState::Proposed;
@@ -389,11 +1206,65 @@ mod tests {
         market.state = MarketState::Active;
         assert!(!market.can_transition_to(MarketState::Proposed));
         assert!(!market.can_transition_to(MarketState::Finalized));
+
+        // Cancellation allowed from every non-terminal state
+        for state in [
+            MarketState::Proposed,
+            MarketState::Approved,
+            MarketState::Active,
+            MarketState::Resolving,
+            MarketState::Disputed,
+        ] {
+            market.state = state;
+            assert!(market.can_transition_to(MarketState::Cancelled));
+        }
+
+        // Terminal states cannot be cancelled
+        market.state = MarketState::Finalized;
+        assert!(!market.can_transition_to(MarketState::Cancelled));
+        market.state = MarketState::Cancelled;
+        assert!(!market.can_transition_to(MarketState::Cancelled));
+        market.state = MarketState::Rejected;
+        assert!(!market.can_transition_to(MarketState::Cancelled));
+        assert!(!market.can_transition_to(MarketState::Approved));
+
+        // Only proposals can be rejected
+        market.state = MarketState::Proposed;
+        assert!(market.can_transition_to(MarketState::Rejected));
+        market.state = MarketState::Approved;
+        assert!(!market.can_transition_to(MarketState::Rejected));
+    }
+
+    #[test]
+    fn test_retire_leaves_tombstone() {
+        let mut market = MarketAccount {
+            market_id: [9; 32],
+            state: MarketState::Cancelled,
+            total_net_contribution: 0,
+            open_positions: 2, // Sold out with shares left, never refunded
+            total_lp_shares: 10_000,
+            creator_lp_shares: 10_000,
+            ..MarketAccount::test_market()
+        };
+
+        market.retire().unwrap();
+        assert_eq!(market.state, MarketState::Closed);
+        assert_eq!(market.market_id, [9; 32]);
+        assert_eq!(market.current_liquidity, 0);
+        assert_eq!(market.open_positions, 0);
+        assert_eq!(market.total_lp_shares, 0);
+
+        // Terminal: no second retirement, and markets in play cannot retire
+        assert!(market.retire().is_err());
+        for state in [MarketState::Approved, MarketState::Active, MarketState::Resolving, MarketState::Disputed] {
+            market.state = state;
+            assert!(market.retire().is_err());
+        }
     }
 
     #[test]
     fn test_proposal_voting() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         // 70% threshold
         market.proposal_likes = 70;
@@ -414,7 +1285,7 @@ mod tests {
 
     #[test]
     fn test_dispute_voting() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         // 60% threshold
         market.dispute_agree = 60;
@@ -431,7 +1302,7 @@ mod tests {
 
     #[test]
     fn test_tradability() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         // Active and not cancelled
         market.state = MarketState::Active;
@@ -450,7 +1321,7 @@ mod tests {
 
     #[test]
     fn test_can_finalize() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         market.state = MarketState::Resolving;
         market.resolution_proposed_at = 1000;
@@ -473,7 +1344,7 @@ mod tests {
 
     #[test]
     fn test_can_dispute() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         market.state = MarketState::Resolving;
         market.resolution_proposed_at = 1000;
@@ -490,9 +1361,38 @@ mod tests {
         assert!(!market.can_dispute(dispute_period, 1000 + 300000));
     }
 
+    #[test]
+    fn test_verify_bounded_loss() {
+        let mut market = MarketAccount::test_market();
+        market.b_parameter = 1_000_000_000_000; // 1000 SOL
+        market.initial_liquidity = 1_000_000_000_000;
+        market.current_liquidity = 400_000_000_000; // 600 SOL lost, bound ≈ 693 SOL
+        assert!(market.verify_bounded_loss().is_ok());
+
+        market.current_liquidity = 200_000_000_000; // 800 SOL lost
+        assert!(market.verify_bounded_loss().is_err());
+
+        // LS-LMSR at depth 1000 SOL, α = 0.05: q0 = 10,000 shares, bound ≈ 10,693 SOL
+        market.curve_type = CurveType::LsLmsr;
+        market.alpha = 50_000_000;
+        assert!(market.verify_bounded_loss().is_ok());
+        market.initial_liquidity = 11_000_000_000_000;
+        market.current_liquidity = 0;
+        assert!(market.verify_bounded_loss().is_err());
+    }
+
+    #[test]
+    fn test_require_not_frozen() {
+        let mut market = MarketAccount::test_market();
+        assert!(market.require_not_frozen().is_ok());
+
+        market.is_frozen = true;
+        assert!(market.require_not_frozen().is_err());
+    }
+
     #[test]
     fn test_total_fees_accumulated() {
-        let mut market = create_test_market();
+        let mut market = MarketAccount::test_market();
 
         market.accumulated_protocol_fees = 1000;
         market.accumulated_resolver_fees = 500;
@@ -501,48 +1401,319 @@ mod tests {
         assert_eq!(market.total_fees_accumulated().unwrap(), 3000);
     }
 
-    // Helper function to create test market
-    fn create_test_market() -> MarketAccount {
-        MarketAccount {
-            market_id: [0; 32],
-            creator: Pubkey::new_unique(),
-            state: MarketState::Proposed,
-            b_parameter: 1000 * 1_000_000_000, // 1000 SOL equivalent
-            initial_liquidity: 10_000_000_000, // 10 SOL
-            current_liquidity: 10_000_000_000,
-            shares_yes: 0,
-            shares_no: 0,
-            total_volume: 0,
-            created_at: 0,
-            approved_at: 0,
-            activated_at: 0,
-            resolution_proposed_at: 0,
-            resolved_at: 0,
-            finalized_at: 0,
-            resolver: Pubkey::default(),
-            proposed_outcome: None,
-            final_outcome: None,
-            ipfs_evidence_hash: [0; 46],
-            dispute_initiated_at: 0,
-            dispute_initiator: Pubkey::default(),
-            accumulated_protocol_fees: 0,
-            accumulated_resolver_fees: 0,
-            accumulated_lp_fees: 0,
-            proposal_likes: 0,
-            proposal_dislikes: 0,
-            proposal_total_votes: 0,
-            resolution_agree: 0,
-            resolution_disagree: 0,
-            resolution_total_votes: 0,
-            dispute_agree: 0,
-            dispute_disagree: 0,
-            dispute_total_votes: 0,
-            was_disputed: false,
-            is_cancelled: false,
-            cancelled_at: None,
-            is_locked: false,
-            reserved: [0; 119],
-            bump: 255,
+    #[test]
+    fn test_unclaimed_resolver_fees() {
+        let mut market = MarketAccount::test_market();
+        market.accumulated_resolver_fees = 500;
+
+        // Valid outcome, not yet claimed
+        market.final_outcome = Some(MarketOutcome::Yes);
+        assert_eq!(market.unclaimed_resolver_fees(), 500);
+
+        // Already claimed
+        market.resolver_fees_claimed = true;
+        assert_eq!(market.unclaimed_resolver_fees(), 0);
+
+        // INVALID outcome forfeits the resolver pool
+        market.resolver_fees_claimed = false;
+        market.final_outcome = Some(MarketOutcome::Invalid);
+        assert_eq!(market.unclaimed_resolver_fees(), 0);
+
+        // Not finalized yet
+        market.final_outcome = None;
+        assert_eq!(market.unclaimed_resolver_fees(), 0);
+    }
+
+    #[test]
+    fn test_outstanding_refunds() {
+        let mut market = MarketAccount::test_market();
+        market.total_net_contribution = 7_000;
+
+        // Active market owes no refunds yet
+        market.state = MarketState::Active;
+        assert_eq!(market.outstanding_refunds(), 0);
+
+        // YES outcome pays winners, not refunds
+        market.state = MarketState::Finalized;
+        market.final_outcome = Some(MarketOutcome::Yes);
+        assert_eq!(market.outstanding_refunds(), 0);
+
+        // INVALID and CANCELLED owe net contributions
+        market.final_outcome = Some(MarketOutcome::Invalid);
+        assert_eq!(market.outstanding_refunds(), 7_000);
+
+        market.final_outcome = None;
+        market.state = MarketState::Cancelled;
+        assert_eq!(market.outstanding_refunds(), 7_000);
+    }
+
+    #[test]
+    fn test_proposal_rejected() {
+        let mut market = MarketAccount::test_market();
+        market.created_at = 1_000;
+        let window_end = 1_000 + MarketAccount::PROPOSAL_VOTING_PERIOD;
+
+        // No votes: rejected only once the window has elapsed
+        assert!(!market.proposal_rejected(7000, window_end - 1));
+        assert!(market.proposal_rejected(7000, window_end));
+
+        // 60% approval is below the 70% threshold
+        market.proposal_likes = 60;
+        market.proposal_dislikes = 40;
+        market.proposal_total_votes = 100;
+        assert!(market.proposal_rejected(7000, window_end));
+
+        // Meeting the threshold keeps the proposal open for admin approval
+        market.proposal_likes = 70;
+        market.proposal_dislikes = 30;
+        assert!(!market.proposal_rejected(7000, window_end));
+
+        // Approved markets are never rejected proposals
+        market.proposal_likes = 0;
+        market.state = MarketState::Approved;
+        assert!(!market.proposal_rejected(7000, window_end));
+    }
+
+    #[test]
+    fn test_can_close() {
+        let mut market = MarketAccount::test_market();
+        market.state = MarketState::Finalized;
+        market.final_outcome = Some(MarketOutcome::Yes);
+        market.finalized_at = 1_000;
+        market.current_liquidity = 5_000;
+        market.open_positions = 2;
+        let claim_period = 86_400;
+        let deadline = 1_000 + claim_period;
+
+        // Unclaimed positions and unwithdrawn liquidity block early close
+        assert!(!market.can_close(claim_period, 1_000));
+        market.open_positions = 0;
+        assert!(!market.can_close(claim_period, 1_000));
+        market.current_liquidity = 0;
+        assert!(market.can_close(claim_period, 1_000));
+
+        // Deadline overrides outstanding claims
+        market.open_positions = 2;
+        assert!(!market.claim_period_ended(claim_period, deadline - 1));
+        assert!(!market.can_close(claim_period, deadline - 1));
+        assert!(market.claim_period_ended(claim_period, deadline));
+        assert!(market.can_close(claim_period, deadline));
+
+        // Legacy markets never counted their positions: wait for the deadline
+        market.open_positions = 0;
+        market.tracks_open_positions = false;
+        assert!(!market.can_close(claim_period, 1_000));
+        assert!(market.can_close(claim_period, deadline));
+        market.tracks_open_positions = true;
+
+        // LPs that have not redeemed keep the market open past the deadline
+        market.total_lp_shares = 3_000;
+        market.creator_lp_shares = 0;
+        assert!(!market.can_close(claim_period, deadline));
+        market.total_lp_shares = 0;
+
+        // Only finalized markets
+        market.state = MarketState::Resolving;
+        assert!(!market.claim_period_ended(claim_period, deadline));
+        assert!(!market.can_close(claim_period, deadline));
+    }
+
+    #[test]
+    fn test_finalize_fixes_claim_deadline() {
+        let mut market = MarketAccount::test_market();
+        market.state = MarketState::Resolving;
+        let claim_period = 86_400;
+
+        market.finalize(MarketOutcome::No, 1_000, claim_period).unwrap();
+        assert_eq!(market.state, MarketState::Finalized);
+        assert_eq!(market.final_outcome, Some(MarketOutcome::No));
+        assert_eq!(market.finalized_at, 1_000);
+        assert_eq!(market.claim_deadline, 1_000 + claim_period);
+
+        // A later config change does not move an already-fixed deadline
+        let shorter = 60;
+        assert!(!market.claim_period_ended(shorter, 1_000 + claim_period - 1));
+        assert!(market.claim_period_ended(shorter, 1_000 + claim_period));
+
+        // Only from RESOLVING or DISPUTED
+        assert!(market.finalize(MarketOutcome::Yes, 2_000, claim_period).is_err());
+    }
+
+    #[test]
+    fn test_add_and_remove_liquidity_scale_pool() {
+        let mut market = MarketAccount::test_market();
+        market.shares_yes = 1_000_000_000_000;
+        market.shares_no = 1_000_000_000_000;
+
+        // Legacy market: creator's pool becomes their LP shares, then 10 SOL doubles it
+        assert_eq!(market.add_liquidity(10_000_000_000).unwrap(), 10_000_000_000);
+        assert_eq!(market.total_lp_shares, 20_000_000_000);
+        assert_eq!(market.creator_lp_shares, 10_000_000_000);
+        assert_eq!(market.current_liquidity, 20_000_000_000);
+        assert_eq!(market.initial_liquidity, 20_000_000_000);
+        assert_eq!(market.b_parameter, 2000 * 1_000_000_000);
+        assert_eq!(market.shares_yes, 2_000_000_000_000);
+        assert_eq!(market.shares_no, market.shares_yes);
+        assert!(market.verify_bounded_loss().is_ok());
+
+        // Removing at par returns the deposit and restores b
+        assert_eq!(market.remove_liquidity_at_par(10_000_000_000).unwrap(), 10_000_000_000);
+        assert_eq!(market.total_lp_shares, 10_000_000_000);
+        assert_eq!(market.current_liquidity, 10_000_000_000);
+        assert_eq!(market.b_parameter, 1000 * 1_000_000_000);
+        assert_eq!(market.shares_yes, 1_000_000_000_000);
+
+        // The last shares cannot be removed at par
+        assert!(market.remove_liquidity_at_par(10_000_000_000).is_err());
+
+        // b may not outgrow MAX_B
+        assert!(market.clone().add_liquidity(10_000_000_000_000).is_err());
+
+        // Only before trading, and never for parimutuel pools
+        market.state = MarketState::Active;
+        assert!(market.add_liquidity(1_000_000_000).is_err());
+        assert!(market.remove_liquidity_at_par(1).is_err());
+        market.state = MarketState::Approved;
+        market.curve_type = CurveType::Parimutuel;
+        assert!(market.add_liquidity(1_000_000_000).is_err());
+    }
+
+    #[test]
+    fn test_winners_pool_excludes_fees() {
+        let mut market = MarketAccount::test_market();
+        market.current_liquidity = 10_000;
+        market.accumulated_resolver_fees = 200;
+        market.accumulated_lp_fees = 500;
+
+        // LP fees belong to LPs
+        assert_eq!(market.winners_pool(), 9_300);
+
+        // Parimutuel bettors split everything but the resolver's cut
+        market.curve_type = CurveType::Parimutuel;
+        assert_eq!(market.winners_pool(), 9_800);
+    }
+
+    #[test]
+    fn test_winners_settled() {
+        let mut market = MarketAccount::test_market();
+        market.state = MarketState::Finalized;
+        market.final_outcome = Some(MarketOutcome::Yes);
+        market.finalized_at = 1_000;
+        market.open_positions = 3;
+        let claim_period = 86_400;
+
+        // Winners can still claim
+        assert!(!market.winners_settled(claim_period, 1_000));
+
+        // Every position settled, or the claim window closed
+        assert!(market.winners_settled(claim_period, 1_000 + claim_period));
+        market.open_positions = 0;
+        assert!(market.winners_settled(claim_period, 1_000));
+
+        // A legacy market's zero counter proves nothing
+        market.tracks_open_positions = false;
+        assert!(!market.winners_settled(claim_period, 1_000));
+        assert!(market.winners_settled(claim_period, 1_000 + claim_period));
+
+        // Refund outcomes are reserved separately
+        market.open_positions = 3;
+        market.final_outcome = Some(MarketOutcome::Invalid);
+        assert!(market.winners_settled(claim_period, 1_000));
+        market.state = MarketState::Cancelled;
+        market.final_outcome = None;
+        assert!(market.winners_settled(claim_period, 1_000));
+    }
+
+    #[test]
+    fn test_redeem_lp_shares_pro_rata() {
+        let mut market = MarketAccount::test_market();
+        market.state = MarketState::Finalized;
+        market.total_lp_shares = 30_000;
+        market.creator_lp_shares = 10_000;
+        market.accumulated_lp_fees = 500;
+
+        // Pro-rata of what is left, so order of redemption does not matter
+        let mut withdrawable = 9_000;
+        let first = market.redeem_lp_shares(20_000, withdrawable).unwrap();
+        assert_eq!(first, 6_000);
+        withdrawable -= first;
+        assert_eq!(market.total_lp_shares, 10_000);
+        assert_eq!(market.current_liquidity, 10_000_000_000);
+
+        assert!(market.redeem_lp_shares(10_001, withdrawable).is_err());
+
+        // Last redemption empties the pool
+        assert_eq!(market.redeem_lp_shares(10_000, withdrawable).unwrap(), 3_000);
+        assert_eq!(market.total_lp_shares, 0);
+        assert_eq!(market.current_liquidity, 0);
+        assert_eq!(market.accumulated_lp_fees, 0);
+    }
+
+    #[test]
+    fn test_market_lock_released_on_every_exit() {
+        fn locked_then_fails(market: &mut Box<MarketAccount>) -> Result<()> {
+            let mut guard = MarketLock::acquire(market)?;
+            guard.current_liquidity = 0;
+            err!(ErrorCode::InsufficientLiquidity)
+        }
+
+        let mut market = Box::new(MarketAccount::test_market());
+        {
+            let guard = MarketLock::acquire(&mut market).unwrap();
+            assert!(guard.is_locked);
+        }
+        assert!(!market.is_locked);
+
+        // Early error return still unlocks, and writes through the guard stick
+        assert!(locked_then_fails(&mut market).is_err());
+        assert!(!market.is_locked);
+        assert_eq!(market.current_liquidity, 0);
+
+        // A market that is already locked cannot be locked again
+        market.is_locked = true;
+        assert!(MarketLock::acquire(&mut market).is_err());
+        assert!(market.is_locked);
+    }
+
+    #[test]
+    fn test_outcome_after_dispute() {
+        let mut market = MarketAccount::test_market();
+        market.proposed_outcome = Some(MarketOutcome::Yes);
+        market.set_dispute_outcome(MarketOutcome::Invalid);
+
+        // YES → INVALID when dispute succeeds
+        assert_eq!(market.outcome_after_dispute(true), Some(MarketOutcome::Invalid));
+        assert_eq!(market.outcome_after_dispute(false), Some(MarketOutcome::Yes));
+
+        // INVALID → NO
+        market.proposed_outcome = Some(MarketOutcome::Invalid);
+        market.set_dispute_outcome(MarketOutcome::No);
+        assert_eq!(market.outcome_after_dispute(true), Some(MarketOutcome::No));
+
+        // Disputed before dispute_outcome was recorded: the proposal flips
+        market.dispute_outcome = 0;
+        market.proposed_outcome = Some(MarketOutcome::Yes);
+        assert_eq!(market.dispute_outcome(), None);
+        assert_eq!(market.outcome_after_dispute(true), Some(MarketOutcome::No));
+        assert_eq!(market.outcome_after_dispute(false), Some(MarketOutcome::Yes));
+    }
+
+    #[test]
+    fn test_dispute_outcome_tag_round_trips() {
+        let mut market = MarketAccount::test_market();
+        for outcome in [MarketOutcome::No, MarketOutcome::Yes, MarketOutcome::Invalid] {
+            market.set_dispute_outcome(outcome);
+            assert_eq!(market.dispute_outcome(), Some(outcome));
         }
     }
+
+    #[test]
+    fn test_outcome_encoding_matches_bool() {
+        // Some(NO)/Some(YES) must serialize like the former Some(false)/Some(true)
+        let no = Some(MarketOutcome::No).try_to_vec().unwrap();
+        let yes = Some(MarketOutcome::Yes).try_to_vec().unwrap();
+
+        assert_eq!(no, Some(false).try_to_vec().unwrap());
+        assert_eq!(yes, Some(true).try_to_vec().unwrap());
+    }
 }
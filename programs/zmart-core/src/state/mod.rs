@@ -4,8 +4,14 @@ pub mod global_config;
 pub mod market;
 pub mod position;
 pub mod vote_record;
+pub mod admin_multisig;
+pub mod pending_config;
+pub mod liquidity_position;
 
 pub use global_config::*;
 pub use market::*;
 pub use position::*;
 pub use vote_record::*;
+pub use admin_multisig::*;
+pub use pending_config::*;
+pub use liquidity_position::*;
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+
+/// Delay between queueing a config change and when it can be applied (48 hours)
+pub const CONFIG_TIMELOCK: i64 = 48 * 60 * 60;
+
+/// Config change queued by update_global_config
+///
+/// Anyone can apply the change via apply_config once `effective_at` has
+/// passed; until then the admin can cancel it. At most one change is queued
+/// at a time, and queueing a new one replaces it and restarts the timelock.
+///
+/// PDA Seeds: ["pending-config"]
+#[account]
+pub struct PendingConfig {
+    /// Whether a change is queued
+    pub is_pending: bool,
+
+    /// Queued protocol fee in basis points
+    pub protocol_fee_bps: u16,
+
+    /// Queued resolver reward in basis points
+    pub resolver_reward_bps: u16,
+
+    /// Queued LP fee in basis points
+    pub liquidity_provider_fee_bps: u16,
+
+    /// Queued proposal approval threshold
+    pub proposal_approval_threshold: u16,
+
+    /// Queued dispute success threshold
+    pub dispute_success_threshold: u16,
+
+    /// Queued minimum resolution delay (None = unchanged)
+    pub min_resolution_delay: Option<i64>,
+
+    /// Queued dispute period (None = unchanged)
+    pub dispute_period: Option<i64>,
+
+    /// Queued claim period (None = unchanged)
+    pub claim_period: Option<i64>,
+
+    /// When the change was queued
+    pub proposed_at: i64,
+
+    /// Earliest time the change can be applied
+    pub effective_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PendingConfig {
+    /// Calculate exact account size
+    pub const LEN: usize = 8   // discriminator
+        + 1                    // is_pending
+        + 2 * 5                // fee and threshold params
+        + 9 * 3                // optional time params
+        + 8                    // proposed_at
+        + 8                    // effective_at
+        + 1;                   // bump
+
+    /// Whether the queued change can be applied at `now`
+    pub fn is_ready(&self, now: i64) -> bool {
+        self.is_pending && now >= self.effective_at
+    }
+
+    /// Write the queued values into `config`
+    pub fn apply_to(&self, config: &mut GlobalConfig) {
+        config.protocol_fee_bps = self.protocol_fee_bps;
+        config.resolver_reward_bps = self.resolver_reward_bps;
+        config.liquidity_provider_fee_bps = self.liquidity_provider_fee_bps;
+        config.proposal_approval_threshold = self.proposal_approval_threshold;
+        config.dispute_success_threshold = self.dispute_success_threshold;
+
+        if let Some(delay) = self.min_resolution_delay {
+            config.min_resolution_delay = delay;
+        }
+        if let Some(period) = self.dispute_period {
+            config.dispute_period = period;
+        }
+        if let Some(period) = self.claim_period {
+            config.claim_period = period;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(effective_at: i64) -> PendingConfig {
+        PendingConfig {
+            is_pending: true,
+            protocol_fee_bps: 100,
+            resolver_reward_bps: 100,
+            liquidity_provider_fee_bps: 100,
+            proposal_approval_threshold: 6000,
+            dispute_success_threshold: 5000,
+            min_resolution_delay: None,
+            dispute_period: Some(3600),
+            claim_period: None,
+            proposed_at: effective_at - CONFIG_TIMELOCK,
+            effective_at,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_len_matches_serialized_size() {
+        let config = PendingConfig {
+            min_resolution_delay: Some(60),
+            claim_period: Some(86400),
+            ..pending(1_000)
+        };
+        assert_eq!(PendingConfig::LEN, 8 + config.try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn test_is_ready_after_timelock() {
+        let config = pending(1_000);
+        assert!(!config.is_ready(999));
+        assert!(config.is_ready(1_000));
+
+        let cancelled = PendingConfig { is_pending: false, ..config };
+        assert!(!cancelled.is_ready(2_000));
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
+use crate::state::MarketOutcome;
 
 /// User's position in a specific market
 ///
@@ -37,8 +38,12 @@ pub struct UserPosition {
     /// Amount claimed (in lamports)
     pub claimed_amount: u64,
 
-    /// Reserved space for future features (64 bytes)
-    pub reserved: [u8; 64],
+    /// Net lamports contributed to the market pool (buys in minus sells out)
+    /// Basis for pro-rata INVALID refunds; never negative
+    pub net_contribution: u64,
+
+    /// Reserved space for future features (56 bytes)
+    pub reserved: [u8; 56],
 
     /// Bump seed for PDA derivation
     pub bump: u8,
@@ -58,7 +63,8 @@ impl UserPosition {
         + 8                         // last_trade_at (i64)
         + 1                         // has_claimed (bool)
         + 8                         // claimed_amount (u64)
-        + 64                        // reserved ([u8; 64])
+        + 8                         // net_contribution (u64)
+        + 56                        // reserved ([u8; 56])
         + 1;                        // bump (u8)
 
     /// Validate reserved fields are zeroed (SECURITY: Finding #12)
@@ -70,7 +76,7 @@ impl UserPosition {
     /// Returns `ErrorCode::InvalidReservedField` if reserved contains non-zero bytes
     pub fn validate_reserved(&self) -> Result<()> {
         require!(
-            self.reserved == [0; 64],
+            self.reserved == [0; 56],
             ErrorCode::InvalidReservedField
         );
         Ok(())
@@ -94,6 +100,24 @@ impl UserPosition {
         self.shares_yes > 0 || self.shares_no > 0
     }
 
+    /// Check if position can be closed without forfeiting a payout
+    ///
+    /// `final_outcome` is the market's final outcome (None until finalized).
+    /// Closable once claimed/refunded, when nothing was ever owed (no shares
+    /// and no net contribution), or when the position holds no winning shares.
+    pub fn can_close(&self, final_outcome: Option<MarketOutcome>) -> bool {
+        if self.has_claimed || (!self.has_shares() && self.net_contribution == 0) {
+            return true;
+        }
+
+        match final_outcome {
+            Some(MarketOutcome::Yes) => self.shares_yes == 0,
+            Some(MarketOutcome::No) => self.shares_no == 0,
+            // INVALID refunds net contribution, which must be claimed first
+            Some(MarketOutcome::Invalid) | None => false,
+        }
+    }
+
     /// Get total shares held (YES + NO)
     pub fn total_shares(&self) -> Result<u64> {
         self.shares_yes
@@ -119,6 +143,28 @@ impl UserPosition {
         Ok(avg as u64)
     }
 
+    /// Calculate pro-rata INVALID refund from net contribution
+    ///
+    /// Formula: refund = net_contribution * available / total_net_contribution,
+    /// capped at net_contribution so no user profits from an INVALID market.
+    ///
+    /// `total_net_contribution` is the sum over all unrefunded positions and
+    /// `available` the lamports the market can pay out, so the sum of all
+    /// refunds can never exceed the market balance.
+    pub fn pro_rata_refund(&self, total_net_contribution: u64, available: u64) -> Result<u64> {
+        if self.net_contribution == 0 || total_net_contribution == 0 {
+            return Ok(0);
+        }
+
+        let share = (self.net_contribution as u128)
+            .checked_mul(available as u128)
+            .ok_or(crate::error::ErrorCode::OverflowError)?
+            .checked_div(total_net_contribution as u128)
+            .ok_or(crate::error::ErrorCode::DivisionByZero)?;
+
+        Ok(share.min(self.net_contribution as u128) as u64)
+    }
+
     /// Check if user has already claimed winnings
     pub fn already_claimed(&self) -> bool {
         self.has_claimed
@@ -137,6 +183,29 @@ impl UserPosition {
     }
 }
 
+#[cfg(test)]
+impl UserPosition {
+    /// Empty position for unit tests
+    ///
+    /// Tests set the fields they exercise with struct update syntax.
+    pub(crate) fn test_position() -> Self {
+        UserPosition {
+            market: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            shares_yes: 0,
+            shares_no: 0,
+            total_invested: 0,
+            trades_count: 0,
+            last_trade_at: 0,
+            has_claimed: false,
+            claimed_amount: 0,
+            net_contribution: 0,
+            reserved: [0; 56],
+            bump: 255,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +215,49 @@ mod tests {
         assert_eq!(UserPosition::LEN, 182);
     }
 
+    #[test]
+    fn test_decodes_baseline_layout() {
+        // Layout positions were written with before net_contribution (64 reserved bytes)
+        #[derive(AnchorSerialize)]
+        struct BaselineUserPosition {
+            market: Pubkey,
+            user: Pubkey,
+            shares_yes: u64,
+            shares_no: u64,
+            total_invested: u64,
+            trades_count: u32,
+            last_trade_at: i64,
+            has_claimed: bool,
+            claimed_amount: u64,
+            reserved: [u8; 64],
+            bump: u8,
+        }
+
+        let baseline = BaselineUserPosition {
+            market: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            shares_yes: 0,
+            shares_no: 700,
+            total_invested: 1_100,
+            trades_count: 3,
+            last_trade_at: 1_730_000_000,
+            has_claimed: false,
+            claimed_amount: 0,
+            reserved: [0; 64],
+            bump: 252,
+        };
+        let mut data = UserPosition::DISCRIMINATOR.to_vec();
+        data.extend(baseline.try_to_vec().unwrap());
+
+        let position = UserPosition::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(position.bump, 252);
+        assert_eq!(position.total_invested, 1_100);
+        assert_eq!(position.trades_count, 3);
+        // Legacy refunds fall back to total_invested (see MarketAccount::refund_owed)
+        assert_eq!(position.net_contribution, 0);
+        assert!(position.validate_reserved().is_ok());
+    }
+
     #[test]
     fn test_calculate_winnings() {
         let position = create_test_position(1000, 500);
@@ -170,6 +282,29 @@ mod tests {
         assert!(position.has_shares());
     }
 
+    #[test]
+    fn test_can_close() {
+        // Nothing ever owed
+        let mut position = create_test_position(0, 0);
+        assert!(position.can_close(None));
+
+        // Sold out at a loss: may still be owed a cancellation refund
+        position.net_contribution = 1_000;
+        assert!(!position.can_close(None));
+        assert!(!position.can_close(Some(MarketOutcome::Invalid)));
+        assert!(position.can_close(Some(MarketOutcome::Yes)));
+
+        // Holding only losing shares
+        let mut position = create_test_position(0, 500);
+        position.net_contribution = 1_000;
+        assert!(position.can_close(Some(MarketOutcome::Yes)));
+        assert!(!position.can_close(Some(MarketOutcome::No)));
+
+        // Claimed positions are always closable
+        position.has_claimed = true;
+        assert!(position.can_close(Some(MarketOutcome::No)));
+    }
+
     #[test]
     fn test_total_shares() {
         let position = create_test_position(1000, 500);
@@ -227,20 +362,47 @@ mod tests {
         assert_eq!(position.net_profit().unwrap(), 0);
     }
 
+    #[test]
+    fn test_pro_rata_refund() {
+        let mut position = create_test_position(1000, 0);
+        position.net_contribution = 300;
+
+        // Fully funded market: refund equals net contribution
+        assert_eq!(position.pro_rata_refund(1000, 1000).unwrap(), 300);
+
+        // Surplus never pays more than net contribution
+        assert_eq!(position.pro_rata_refund(1000, 5000).unwrap(), 300);
+
+        // Shortfall is shared pro-rata (300/1000 of 500)
+        assert_eq!(position.pro_rata_refund(1000, 500).unwrap(), 150);
+
+        // Fully sold position gets nothing
+        position.net_contribution = 0;
+        assert_eq!(position.pro_rata_refund(1000, 1000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_pro_rata_refunds_never_exceed_balance() {
+        let contributions = [700u64, 200, 100];
+        let mut total: u64 = contributions.iter().sum();
+        let mut available = 600u64; // Underfunded market
+
+        for c in contributions {
+            let mut position = create_test_position(1, 0);
+            position.net_contribution = c;
+            let refund = position.pro_rata_refund(total, available).unwrap();
+            assert!(refund <= available);
+            available -= refund;
+            total -= c;
+        }
+    }
+
     // Helper function to create test position
     fn create_test_position(shares_yes: u64, shares_no: u64) -> UserPosition {
         UserPosition {
-            market: Pubkey::new_unique(),
-            user: Pubkey::new_unique(),
             shares_yes,
             shares_no,
-            total_invested: 0,
-            trades_count: 0,
-            last_trade_at: 0,
-            has_claimed: false,
-            claimed_amount: 0,
-            reserved: [0; 64],
-            bump: 255,
+            ..UserPosition::test_position()
         }
     }
 }
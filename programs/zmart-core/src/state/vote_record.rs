@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::MarketState;
 
 /// Type of vote being recorded
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -49,4 +50,18 @@ impl VoteRecord {
         8 +                      // voted_at
         1;                       // bump
     // Total: 83 bytes
+
+    /// Check if the voting phase this record belongs to is over
+    ///
+    /// - Proposal votes: once the market has left PROPOSED
+    /// - Dispute votes: once the market is FINALIZED, CANCELLED or CLOSED
+    pub fn can_close(&self, market_state: MarketState) -> bool {
+        match self.vote_type {
+            VoteType::Proposal => market_state != MarketState::Proposed,
+            VoteType::Dispute => matches!(
+                market_state,
+                MarketState::Finalized | MarketState::Cancelled | MarketState::Closed
+            ),
+        }
+    }
 }